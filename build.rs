@@ -0,0 +1,27 @@
+//! Lists the modules every solution is built on, for the result cache to hash: each file in `src`
+//! and the day registry, but not the days themselves, which are hashed one by one.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    // Also run again when a module is added or removed, not only when a listed one changes.
+    println!("cargo:rerun-if-changed=src");
+
+    let src = Path::new(&env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    let mut modules: Vec<PathBuf> = fs::read_dir(&src)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "rs"))
+        .chain([src.join("days").join("mod.rs")])
+        .collect();
+    modules.sort();
+
+    let list: String = modules
+        .iter()
+        .map(|path| format!("    include_str!({:?}),\n", path))
+        .collect();
+    let out = Path::new(&env::var_os("OUT_DIR").unwrap()).join("shared_sources.rs");
+    fs::write(out, format!("[\n{}]\n", list)).unwrap();
+}
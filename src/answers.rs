@@ -0,0 +1,82 @@
+use crate::toml::Table;
+use crate::{AocError, InputSource, Part};
+use std::path::{Path, PathBuf};
+
+/// The known answers for one input, kept in `answers/<input name>.toml` next to the inputs directory.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Answers(Table);
+
+impl Answers {
+    /// Where the answers for `source` live, e.g. `answers/day03.toml` for `inputs/day03`.
+    /// Stdin has no answers file.
+    pub fn path_for(source: &InputSource) -> Option<PathBuf> {
+        let InputSource::File(input) = source else {
+            return None;
+        };
+        let name = input.file_name()?.to_string_lossy();
+        let input_dir = input.parent().unwrap_or(Path::new(""));
+        // A bare file name is an input in the working directory, whose answers are in ./answers.
+        let answers_dir = input_dir.parent().unwrap_or(input_dir).join("answers");
+        Some(answers_dir.join(format!("{}.toml", name)))
+    }
+
+    /// Loads the answers for `source`; a missing file just means nothing is known yet.
+    pub fn load(source: &InputSource) -> Result<Answers, AocError> {
+        let path = Answers::path_for(source)
+            .ok_or_else(|| AocError::invalid(format!("{} has no answers file", source)))?;
+        Ok(Answers(Table::load(path)?))
+    }
+
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key)
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.0.set(key, value)
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.get(part_key(part))
+    }
+
+    pub fn set_answer(&mut self, part: Part, answer: &str) {
+        self.set(part_key(part), answer)
+    }
+
+    pub fn save(&self) -> Result<(), AocError> {
+        self.0.save()
+    }
+}
+
+fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part1",
+        Part::Two => "part2",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_for(input: &str) -> Option<PathBuf> {
+        Answers::path_for(&InputSource::File(PathBuf::from(input)))
+    }
+
+    #[test]
+    fn answers_sit_next_to_the_inputs_directory() {
+        let expected = |path: &str| Some(PathBuf::from(path));
+        assert_eq!(path_for("inputs/day03"), expected("answers/day03.toml"));
+        assert_eq!(path_for("./inputs/day03"), expected("./answers/day03.toml"));
+        assert_eq!(
+            path_for("/aoc/inputs/day03"),
+            expected("/aoc/answers/day03.toml")
+        );
+        assert_eq!(path_for("day03"), expected("answers/day03.toml"));
+        assert_eq!(Answers::path_for(&InputSource::Stdin), None);
+    }
+}
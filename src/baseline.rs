@@ -0,0 +1,288 @@
+use crate::toml::Table;
+use crate::{build_profile, cache_dir, AocError, InputSource, Part, Stats};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// Benchmark timings saved under a name, to compare later benchmarks with. Kept in
+/// `baselines/<name>.toml` in the cache directory, since timings only mean something on the machine
+/// that took them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Baseline {
+    name: String,
+    table: Table,
+}
+
+impl Baseline {
+    /// A baseline that has nothing saved yet, or what is saved under `name` when `existing` is set.
+    /// Saving timings again replaces those of the same inputs and keeps the rest.
+    pub fn open(name: &str, existing: bool) -> Result<Baseline, AocError> {
+        if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(AocError::Config(format!(
+                "invalid baseline name {:?}",
+                name
+            )));
+        }
+        let path = cache_dir().join("baselines").join(format!("{}.toml", name));
+        if existing && !path.exists() {
+            return Err(AocError::Config(format!(
+                "no baseline {:?}, save one with --save-baseline {}",
+                name, name
+            )));
+        }
+        Ok(Baseline {
+            name: String::from(name),
+            table: Table::load(path)?,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn profile(&self) -> Option<&str> {
+        self.table.get("profile")
+    }
+
+    pub fn stats(
+        &self,
+        input: &InputSource,
+        part: Option<Part>,
+    ) -> Result<Option<Stats>, AocError> {
+        let key = stats_key(input, part);
+        self.table
+            .get(&key)
+            .map(|value| {
+                parse_stats(value).map_err(|msg| {
+                    AocError::parse(format!("{} in baseline {}: {}", key, self.name, msg))
+                })
+            })
+            .transpose()
+    }
+
+    pub fn set_stats(&mut self, input: &InputSource, part: Option<Part>, stats: &Stats) {
+        self.table.set("profile", build_profile());
+        let nanos = |d: Duration| d.as_nanos();
+        let value = format!(
+            "runs={} min_ns={} median_ns={} mean_ns={} p95_ns={} std_dev_ns={}",
+            stats.runs,
+            nanos(stats.min),
+            nanos(stats.median),
+            nanos(stats.mean),
+            nanos(stats.p95),
+            nanos(stats.std_dev)
+        );
+        self.table.set(&stats_key(input, part), &value);
+    }
+
+    pub fn save(&self) -> Result<(), AocError> {
+        self.table.save()
+    }
+}
+
+// `day16-<location>.parse`, `day16-<location>.part1`, `day21_test-<location>.part2`, ...
+// where the location tells apart inputs of the same name in different directories.
+fn stats_key(input: &InputSource, part: Option<Part>) -> String {
+    let input = input.unique_id();
+    match part {
+        Some(part) => format!("{}.part{}", input, part.number()),
+        None => format!("{}.parse", input),
+    }
+}
+
+fn parse_stats(value: &str) -> Result<Stats, String> {
+    let field = |name: &str| -> Result<u64, String> {
+        value
+            .split_whitespace()
+            .find_map(|f| f.strip_prefix(name)?.strip_prefix('='))
+            .ok_or(format!("no {}", name))?
+            .parse()
+            .map_err(|_| format!("invalid {}", name))
+    };
+    let time = |name: &str| field(name).map(Duration::from_nanos);
+    Ok(Stats {
+        runs: field("runs")? as usize,
+        min: time("min_ns")?,
+        median: time("median_ns")?,
+        mean: time("mean_ns")?,
+        p95: time("p95_ns")?,
+        std_dev: time("std_dev_ns")?,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub before: Stats,
+    pub after: Stats,
+    /// The relative change of the mean time, e.g. `0.2` for 20% slower.
+    pub relative: f64,
+    /// Whether the means differ by more than noise: Welch's t-test at the 5% level, and a relative
+    /// change of at least the threshold the change was made with.
+    pub significant: bool,
+}
+
+impl Change {
+    /// Compares timings with those of a baseline. Changes smaller than `threshold`, e.g. `0.05` for
+    /// 5%, count as noise however consistent they are.
+    pub fn new(before: Stats, after: Stats, threshold: f64) -> Change {
+        let (m1, m2) = (before.mean.as_secs_f64(), after.mean.as_secs_f64());
+        let relative = if m1 > 0.0 { m2 / m1 - 1.0 } else { 0.0 };
+        Change {
+            before,
+            after,
+            relative,
+            significant: relative.abs() >= threshold && welch_differs(&before, &after),
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        self.significant && self.relative > 0.0
+    }
+
+    pub fn is_improvement(&self) -> bool {
+        self.significant && self.relative < 0.0
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let verdict = if self.is_regression() {
+            "REGRESSION"
+        } else if self.is_improvement() {
+            "improvement"
+        } else {
+            "no significant change"
+        };
+        write!(
+            f,
+            "mean {:.1?} -> {:.1?} ({:+.1}%), {}",
+            self.before.mean,
+            self.after.mean,
+            self.relative * 100.0,
+            verdict
+        )
+    }
+}
+
+// Welch's t-test: whether two sets of runs with possibly different spreads have different means, at
+// the 5% level. Without at least two runs on both sides there is no spread to compare with.
+fn welch_differs(a: &Stats, b: &Stats) -> bool {
+    if a.runs < 2 || b.runs < 2 {
+        return false;
+    }
+    let spread = |s: &Stats| s.std_dev.as_secs_f64().powi(2) / s.runs as f64;
+    let (va, vb) = (spread(a), spread(b));
+    let diff = (a.mean.as_secs_f64() - b.mean.as_secs_f64()).abs();
+    if va + vb == 0.0 {
+        return diff > 0.0;
+    }
+    let t = diff / (va + vb).sqrt();
+    // Welch–Satterthwaite degrees of freedom.
+    let df =
+        (va + vb).powi(2) / (va.powi(2) / (a.runs - 1) as f64 + vb.powi(2) / (b.runs - 1) as f64);
+    t > t_critical(df)
+}
+
+// The two-sided 5% critical value of Student's t distribution, rounding the degrees of freedom down.
+fn t_critical(df: f64) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+    match df.floor() as usize {
+        0 => TABLE[0],
+        df @ 1..=30 => TABLE[df - 1],
+        31..=40 => 2.042,
+        41..=60 => 2.021,
+        61..=120 => 2.000,
+        _ => 1.980,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{set_env, TempDir};
+    use crate::CACHE_DIR_VAR;
+    use std::fs;
+
+    fn stats(runs: usize, mean_us: u64, std_dev_us: u64) -> Stats {
+        let mean = Duration::from_micros(mean_us);
+        Stats {
+            runs,
+            min: mean.saturating_sub(Duration::from_micros(std_dev_us)),
+            median: mean,
+            mean,
+            p95: mean + Duration::from_micros(2 * std_dev_us),
+            std_dev: Duration::from_micros(std_dev_us),
+        }
+    }
+
+    #[test]
+    fn t_critical_follows_the_table() {
+        assert_eq!(t_critical(0.4), 12.706);
+        assert_eq!(t_critical(1.0), 12.706);
+        assert_eq!(t_critical(9.9), 2.262);
+        assert_eq!(t_critical(30.0), 2.042);
+        assert_eq!(t_critical(45.0), 2.021);
+        assert_eq!(t_critical(1e6), 1.980);
+    }
+
+    #[test]
+    fn tells_a_clear_difference_from_noise() {
+        // 100µs against 120µs, with a spread of 2µs over 20 runs each: t is about 32.
+        assert!(welch_differs(&stats(20, 100, 2), &stats(20, 120, 2)));
+        // The same means with a spread of 30µs: t is about 2.1 on 38 degrees of freedom.
+        assert!(welch_differs(&stats(20, 100, 30), &stats(20, 120, 30)));
+        // And over 5 runs each: t is about 1.05.
+        assert!(!welch_differs(&stats(5, 100, 30), &stats(5, 120, 30)));
+        assert!(!welch_differs(&stats(20, 100, 30), &stats(20, 101, 30)));
+    }
+
+    #[test]
+    fn needs_two_runs_on_both_sides() {
+        assert!(!welch_differs(&stats(1, 100, 0), &stats(20, 200, 1)));
+        assert!(!welch_differs(&stats(20, 100, 1), &stats(1, 200, 0)));
+        let change = Change::new(stats(1, 100, 0), stats(1, 200, 0), 0.05);
+        assert!(!change.significant);
+        assert!(!change.is_regression());
+    }
+
+    #[test]
+    fn a_significant_change_must_reach_the_threshold() {
+        let (before, after) = (stats(20, 100, 1), stats(20, 103, 1));
+        assert!(welch_differs(&before, &after));
+        assert!(Change::new(before, after, 0.02).is_regression());
+        assert!(!Change::new(before, after, 0.05).significant);
+        assert!(Change::new(after, before, 0.02).is_improvement());
+    }
+
+    #[test]
+    fn keeps_inputs_of_the_same_name_apart() {
+        let dir = TempDir::new("baseline");
+        let cache = dir.path().join("cache");
+        let _env = set_env(&[(CACHE_DIR_VAR, &cache)]);
+        let inputs: Vec<InputSource> = ["a", "b"]
+            .iter()
+            .map(|name| {
+                let path = dir.path().join(name).join("day05");
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, "seeds: 1 2\n").unwrap();
+                InputSource::File(path)
+            })
+            .collect();
+
+        let mut baseline = Baseline::open("main", false).unwrap();
+        baseline.set_stats(&inputs[0], Some(Part::Two), &stats(10, 100, 5));
+        baseline.set_stats(&inputs[1], Some(Part::Two), &stats(10, 900, 5));
+        baseline.save().unwrap();
+
+        let saved = Baseline::open("main", true).unwrap();
+        assert_eq!(saved.profile(), Some(build_profile()));
+        let mean = |input| saved.stats(input, Some(Part::Two)).unwrap().unwrap().mean;
+        assert_eq!(mean(&inputs[0]), Duration::from_micros(100));
+        assert_eq!(mean(&inputs[1]), Duration::from_micros(900));
+        assert_eq!(saved.stats(&inputs[0], None).unwrap(), None);
+        assert!(Baseline::open("other", true).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary of repeated timings of the same piece of work.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    /// The sample standard deviation, zero for a single run.
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// `None` when there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        // Nearest rank: the smallest sample that is at least as slow as 95% of them.
+        let p95 = samples[(runs * 95).div_ceil(100) - 1];
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+        let variance = match runs {
+            1 => 0.0,
+            _ => {
+                let squares: f64 = samples
+                    .iter()
+                    .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                    .sum();
+                squares / (runs - 1) as f64
+            }
+        };
+
+        Some(Stats {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+            p95,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.1?}, median {:.1?}, mean {:.1?}, p95 {:.1?} ({} runs)",
+            self.min, self.median, self.mean, self.p95, self.runs
+        )
+    }
+}
+
+/// Runs `routine` `warmup` times untimed, then `runs` times timed. Returns the last result with
+/// the timings. `runs` must be at least 1.
+pub fn bench<T>(warmup: usize, runs: usize, mut routine: impl FnMut() -> T) -> (T, Stats) {
+    bench_with_setup(warmup, runs, || (), |_| routine())
+}
+
+/// Like [`bench`], but feeds every run a fresh value from `setup`, which is not timed.
+/// Dropping the result is not timed either.
+pub fn bench_with_setup<I, T>(
+    warmup: usize,
+    runs: usize,
+    mut setup: impl FnMut() -> I,
+    mut routine: impl FnMut(I) -> T,
+) -> (T, Stats) {
+    assert!(runs > 0, "cannot benchmark zero runs");
+
+    for _ in 0..warmup {
+        black_box(routine(black_box(setup())));
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+    let mut last = None;
+    for _ in 0..runs {
+        let input = black_box(setup());
+        let start = Instant::now();
+        let result = black_box(routine(input));
+        samples.push(start.elapsed());
+        last = Some(result);
+    }
+
+    let stats = Stats::from_samples(samples).unwrap();
+    (last.unwrap(), stats)
+}
+
+/// The cargo profile this binary was built with, as far as it can tell.
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
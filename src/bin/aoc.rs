@@ -0,0 +1,1005 @@
+use aoc2023::days::{self, Day};
+use aoc2023::progress;
+use aoc2023::runner::{self, Isolated, Limits};
+use aoc2023::{
+    bench, bench_with_setup, build_profile, cache_dir, compare_runs, example_path,
+    extract_examples, fetch_input, fetch_leaderboard, generator, new_day, read_lines, save_example,
+    solution_path, submit_answer, timing_tree, Answers, AocError, Baseline, Change, Config,
+    Fetched, Format, InputSource, Leaderboard, Part, Record, ResultCache, Saved, Stats, Unsolved,
+    Verdict, DEFAULT_EXAMPLE,
+};
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+use std::process::{self, ExitCode};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage: aoc run <day|all> [options] [--format <text|table|json|csv>]
+                                    [--jobs <n>] [--timeout <secs>] [--spans] [--no-cache]
+       aoc bench <day|all> [options] [--warmup <n>] [--runs <n>] [--timeout <secs>] [--compare]
+                                      [--save-baseline <name>] [--baseline <name>]
+                                      [--threshold <percent>]
+       aoc check <day|all> [options] [--record]
+       aoc fetch <day|all>
+       aoc submit <day> <1|2>
+       aoc leaderboard <file.json|id> [--timeout <secs>]
+       aoc examples <day> <page.html> [--force]
+       aoc new <day>
+       aoc watch <day> [options]
+       aoc generate <day|all> [--seed <n>] [--size <n>] [--save <name>]
+
+Options:
+  --input <path|->   read a single day's input from a file, or from stdin when given -
+  --example [name]   read the example input dayNN_<name> instead, dayNN_test by default
+  --part <1|2>       run only the given part
+
+Inputs are read from ./inputs/dayNN, or from $AOC_INPUT_DIR/dayNN when set.
+run prints the answers as text by default; the other formats print one record per part once all
+days are done. --jobs runs the days on n threads and prints a summary table with the total runtime.
+Every part runs on its own thread: a panic, or no answer within --timeout, fails only that part.
+Long loops show a progress line; Ctrl-C stops the running part and skips the rest.
+--spans ends the run with a tree per day of where the time went, down to the spans solutions enter.
+Answers are cached in ./.aoc-cache (or $AOC_CACHE_DIR), and shown again without solving as long as
+the input and the solution's source are unchanged. --no-cache solves every part; so does --spans.
+bench runs parsing and every part --warmup times untimed (default 2), then --runs times
+(default 10), and reports the spread of the timed runs. Like run, it benches every part on its own
+thread, and gives up on a part when a run takes longer than --timeout (default 5 seconds) or on
+Ctrl-C. --compare races every implementation of a
+part instead, checks that they agree with the reference one, and ranks them by median time.
+--save-baseline stores the timings under a name in the cache directory; --baseline compares with
+the timings stored under a name, and fails when a part or parse got significantly slower: by
+Welch's t-test, and by at least --threshold percent (default 5).
+check compares the answers with answers/<input name>.toml next to the inputs directory. Answers that
+are not stored yet are recorded with --record, or after asking when run in a terminal.
+fetch downloads missing inputs into the inputs directory, using the session token from ./aoc.toml
+(or $AOC_CONFIG) or $AOC_SESSION. Inputs already there are never downloaded again.
+submit solves a part and posts the answer to the same server. The verdict is recorded in the
+answers file, and an answer known to be wrong, too high or too low is never sent again.
+leaderboard reads a private leaderboard from a saved JSON file, or fetches it by id, and prints the
+rankings and star times next to the runtime of our solutions (each part limited to --timeout,
+default 5 seconds).
+examples extracts the example inputs and their answers from a saved puzzle page, and writes them as
+dayNN_test, dayNN_test2, ... next to the inputs, with the answers for check --example. Fixtures that
+are already there and differ are kept unless --force is given.
+new starts a day: src/days/dayNN.rs with a Solution skeleton and example tests, registered with the
+runner, and empty input and example files to fill in, e.g. with fetch and examples.
+watch reruns a day whenever its source file or input changes, after rebuilding, and shows the
+answers and solve times next to those of the run before.
+generate makes a random input that the day's solutions can solve, the same one for the same --seed
+(default 1) and --size. The size counts what the day's input is made of, e.g. rows or the side of
+the grid, and defaults to that of the official input. The input is printed, or saved as
+dayNN_<name> next to the inputs with --save, to run and bench with --example <name>.";
+
+struct RunOptions {
+    input: Option<InputSource>,
+    example: Option<String>,
+    parts: Vec<Part>,
+}
+
+impl RunOptions {
+    fn parse(args: &[&str]) -> Result<RunOptions, String> {
+        let mut options = RunOptions {
+            input: None,
+            example: None,
+            parts: Part::ALL.to_vec(),
+        };
+        let mut args = args.iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match *arg {
+                "--input" => {
+                    let value = args.next().ok_or("--input needs a path, or - for stdin")?;
+                    options.input = Some(InputSource::from(*value));
+                }
+                "--example" => {
+                    let name = args.next_if(|a| !a.starts_with("--"));
+                    options.example = Some(String::from(*name.unwrap_or(&DEFAULT_EXAMPLE)));
+                }
+                "--part" => {
+                    let value = args.next().ok_or("--part needs 1 or 2")?;
+                    options.parts = vec![value.parse().map_err(|e| format!("{}", e))?];
+                }
+                other => return Err(format!("Unknown option: {}\n\n{}", other, USAGE)),
+            }
+        }
+
+        if options.input.is_some() && options.example.is_some() {
+            return Err(String::from("--input and --example cannot be combined"));
+        }
+        Ok(options)
+    }
+
+    fn input_for(&self, day: u8) -> InputSource {
+        match (&self.input, &self.example) {
+            (Some(input), _) => input.clone(),
+            (None, Some(name)) => InputSource::example(day, name),
+            (None, None) => InputSource::for_day(day),
+        }
+    }
+}
+
+struct BenchOptions {
+    warmup: usize,
+    runs: usize,
+    timeout: Duration,
+    compare: bool,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    /// The smallest relative change against the baseline that counts.
+    threshold: f64,
+}
+
+impl BenchOptions {
+    /// Takes the bench options out of `args`, leaving the rest for `RunOptions`.
+    fn parse<'a>(args: &[&'a str]) -> Result<(BenchOptions, Vec<&'a str>), String> {
+        let mut options = BenchOptions {
+            warmup: 2,
+            runs: 10,
+            timeout: Duration::from_secs(5),
+            compare: false,
+            save_baseline: None,
+            baseline: None,
+            threshold: 0.05,
+        };
+        let mut rest = Vec::new();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match *arg {
+                "--warmup" => options.warmup = count_arg("--warmup", args.next())?,
+                "--runs" => options.runs = count_arg("--runs", args.next())?,
+                "--timeout" => {
+                    let value = args.next().ok_or("--timeout needs a number of seconds")?;
+                    options.timeout = timeout_arg(value)?;
+                }
+                "--compare" => options.compare = true,
+                "--save-baseline" => {
+                    let name = args.next().ok_or("--save-baseline needs a name")?;
+                    options.save_baseline = Some(String::from(*name));
+                }
+                "--baseline" => {
+                    let name = args.next().ok_or("--baseline needs a name")?;
+                    options.baseline = Some(String::from(*name));
+                }
+                "--threshold" => {
+                    let value = args.next().ok_or("--threshold needs a percentage")?;
+                    options.threshold = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|p| *p >= 0.0)
+                        .ok_or(format!("--threshold needs a percentage, got {}", value))?
+                        / 100.0;
+                }
+                other => rest.push(other),
+            }
+        }
+
+        if options.runs == 0 {
+            return Err(String::from("--runs must be at least 1"));
+        }
+        if options.compare && (options.save_baseline.is_some() || options.baseline.is_some()) {
+            return Err(String::from("--compare cannot be combined with baselines"));
+        }
+        Ok((options, rest))
+    }
+}
+
+fn take_value<'a>(args: &[&'a str], name: &str) -> Result<(Option<&'a str>, Vec<&'a str>), String> {
+    match args.iter().position(|a| *a == name) {
+        Some(idx) => {
+            let value = args.get(idx + 1).ok_or(format!("{} needs a value", name))?;
+            let rest = [&args[..idx], &args[idx + 2..]].concat();
+            Ok((Some(*value), rest))
+        }
+        None => Ok((None, args.to_vec())),
+    }
+}
+
+fn count_arg(name: &str, value: Option<&&str>) -> Result<usize, String> {
+    value
+        .and_then(|v| v.parse().ok())
+        .ok_or(format!("{} needs a number", name))
+}
+
+fn read_input(day: &Day, input: &InputSource) -> Result<Vec<String>, AocError> {
+    println!("Day {:02}", day.number);
+    Ok(read_lines(input)?.collect())
+}
+
+/// The baselines of a bench run: the one timings are saved to, and the one they are compared with.
+struct Baselines {
+    save: RefCell<Option<Baseline>>,
+    compare: Option<Baseline>,
+    threshold: f64,
+    regressions: Cell<usize>,
+}
+
+impl Baselines {
+    /// Saves `stats` and compares them with the baseline, returning the comparison to print.
+    fn check(
+        &self,
+        input: &InputSource,
+        part: Option<Part>,
+        stats: &Stats,
+    ) -> Result<String, AocError> {
+        if let Some(save) = self.save.borrow_mut().as_mut() {
+            save.set_stats(input, part, stats);
+        }
+        let Some(baseline) = &self.compare else {
+            return Ok(String::new());
+        };
+        let Some(before) = baseline.stats(input, part)? else {
+            return Ok(format!("\n  vs {}: not in the baseline", baseline.name()));
+        };
+        let change = Change::new(before, *stats, self.threshold);
+        if change.is_regression() {
+            self.regressions.set(self.regressions.get() + 1);
+        }
+        Ok(format!("\n  vs {}: {}", baseline.name(), change))
+    }
+}
+
+/// Runs a benchmark on its own thread, the way `run` solves a part: a panic, a run slower than the
+/// timeout or Ctrl-C fails only this benchmark.
+fn isolate_bench<T, F>(day: &Day, what: &str, options: &BenchOptions, work: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let limits = Limits {
+        // Every run gets the timeout, the untimed warm-up runs too.
+        timeout: Some(options.timeout * (options.warmup + options.runs) as u32),
+        show_progress: io::stderr().is_terminal(),
+    };
+    let label = format!("Day {:02} {}", day.number, what);
+    match runner::isolate(&label, limits, work) {
+        Isolated::Done(result) => Ok(result),
+        Isolated::Panicked(msg) => Err(format!("{} panicked: {}", what, msg)),
+        Isolated::TimedOut => Err(format!(
+            "{}: {} runs not done within {:.1?} each",
+            what,
+            options.warmup + options.runs,
+            options.timeout
+        )),
+        Isolated::Interrupted => Err(format!("{}: interrupted", what)),
+    }
+}
+
+fn bench_day(
+    day: &'static Day,
+    options: &RunOptions,
+    bench_options: &BenchOptions,
+    baselines: &Baselines,
+) -> Result<(), String> {
+    let BenchOptions { warmup, runs, .. } = *bench_options;
+    let source = options.input_for(day.number);
+    let lines = read_input(day, &source).map_err(|e| e.to_string())?;
+    // Parsing consumes the lines, so every run gets its own copy; copying is not timed.
+    let (parsed, stats) = isolate_bench(day, "Parse", bench_options, move || {
+        bench_with_setup(warmup, runs, || lines.clone(), |l| day.parse(l))
+    })?;
+    let input = Arc::new(parsed.map_err(|e| e.to_string())?);
+    let change = baselines
+        .check(&source, None, &stats)
+        .map_err(|e| e.to_string())?;
+    println!("Parse: {}{}", stats, change);
+
+    for &part in &options.parts {
+        let input = Arc::clone(&input);
+        let (answer, stats) = isolate_bench(day, &part.to_string(), bench_options, move || {
+            bench(warmup, runs, || day.solve(&input, part))
+        })?;
+        let change = baselines
+            .check(&source, Some(part), &stats)
+            .map_err(|e| e.to_string())?;
+        println!("{}: Result: {}, {}{}", part, answer, stats, change);
+    }
+    Ok(())
+}
+
+/// Benchmarks the reference implementation of every part against its variants.
+fn compare_day(
+    day: &'static Day,
+    options: &RunOptions,
+    bench_options: &BenchOptions,
+    disagreements: &Cell<usize>,
+) -> Result<(), String> {
+    let BenchOptions { warmup, runs, .. } = *bench_options;
+    let lines = read_input(day, &options.input_for(day.number)).map_err(|e| e.to_string())?;
+    let input = Arc::new(day.parse(lines).map_err(|e| e.to_string())?);
+
+    for &part in &options.parts {
+        println!("{}:", part);
+        let reference_input = Arc::clone(&input);
+        let (reference, stats) = isolate_bench(day, &part.to_string(), bench_options, move || {
+            bench(warmup, runs, || day.solve(&reference_input, part))
+        })?;
+        let mut ranking = vec![("reference", reference.clone(), stats)];
+        for name in day.variants(part) {
+            let what = format!("{} {}", part, name);
+            let input = Arc::clone(&input);
+            let (answer, stats) = isolate_bench(day, &what, bench_options, move || {
+                bench(warmup, runs, || day.solve_variant(&input, part, name))
+            })?;
+            let answer = answer.unwrap_or_default();
+            if answer == Unsolved.to_string() {
+                println!("  {}: does not handle this input", name);
+                continue;
+            }
+            ranking.push((name, answer, stats));
+        }
+        ranking.sort_by_key(|(_, _, stats)| stats.median);
+
+        for (rank, (name, answer, stats)) in ranking.iter().enumerate() {
+            let verdict = if *answer == reference {
+                ""
+            } else {
+                disagreements.set(disagreements.get() + 1);
+                " DISAGREES"
+            };
+            println!("  {}. {}: {}{}, {}", rank + 1, name, answer, verdict, stats);
+        }
+    }
+    Ok(())
+}
+
+struct Missing {
+    input: InputSource,
+    part: Part,
+    answer: String,
+}
+
+fn check_day(
+    day: &Day,
+    options: &RunOptions,
+    mismatches: &Cell<usize>,
+    missing: &RefCell<Vec<Missing>>,
+) -> Result<(), AocError> {
+    let source = options.input_for(day.number);
+    let answers = Answers::load(&source)?;
+    let input = day.parse(read_input(day, &source)?)?;
+
+    for part in &options.parts {
+        let answer = day.solve(&input, *part);
+        match answers.answer(*part) {
+            Some(expected) if expected == answer => println!("{}: ok ({})", part, answer),
+            Some(expected) => {
+                println!("{}: MISMATCH, got {}, expected {}", part, answer, expected);
+                mismatches.set(mismatches.get() + 1);
+            }
+            None if answer == Unsolved.to_string() => println!("{}: unsolved", part),
+            None => {
+                println!("{}: no stored answer, got {}", part, answer);
+                missing.borrow_mut().push(Missing {
+                    input: source.clone(),
+                    part: *part,
+                    answer,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn confirm(question: &str) -> bool {
+    if !io::stdin().is_terminal() {
+        return false;
+    }
+    print!("{} [y/N] ", question);
+    let _ = io::stdout().flush();
+    let mut reply = String::new();
+    io::stdin().lock().read_line(&mut reply).is_ok() && reply.trim().eq_ignore_ascii_case("y")
+}
+
+fn record(missing: &[Missing]) -> Result<(), AocError> {
+    for m in missing {
+        let mut answers = Answers::load(&m.input)?;
+        answers.set_answer(m.part, &m.answer);
+        answers.save()?;
+        println!(
+            "Recorded {} for {}: {} in {}",
+            m.part,
+            m.input,
+            m.answer,
+            answers.path().display()
+        );
+    }
+    Ok(())
+}
+
+fn select_days(target: &str, options: &RunOptions) -> Result<Vec<&'static Day>, String> {
+    if target == "all" {
+        if options.input.is_some() {
+            return Err(String::from(
+                "--input only works for a single day, use $AOC_INPUT_DIR instead",
+            ));
+        }
+
+        // Most days have no example input, only run the ones that do.
+        return Ok(days::DAYS
+            .iter()
+            .filter(|day| options.example.is_none() || options.input_for(day.number).exists())
+            .collect());
+    }
+
+    let number: u8 = target
+        .parse()
+        .map_err(|_| format!("Invalid day: {}", target))?;
+    let day = days::find(number).ok_or(format!("Day {} is not registered", number))?;
+    Ok(vec![day])
+}
+
+/// A day of the calendar, 1 to 25, whether or not it has been solved yet.
+fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse() {
+        Ok(number @ 1..=25) => Ok(number),
+        _ => Err(format!("Invalid day: {}", day)),
+    }
+}
+
+fn day_error(day: u8, input: &InputSource, msg: impl Display) -> String {
+    format!("Day {:02} ({}): {}", day, input, msg)
+}
+
+fn for_days<F, E>(target: &str, options: &RunOptions, action: F) -> Result<(), String>
+where
+    F: Fn(&'static Day) -> Result<(), E>,
+    E: Display,
+{
+    let run_one = |day: &'static Day| {
+        action(day).map_err(|e| day_error(day.number, &options.input_for(day.number), e))
+    };
+
+    let days = select_days(target, options)?;
+    if target != "all" {
+        return days.iter().try_for_each(|day| run_one(day));
+    }
+
+    let failed = days
+        .iter()
+        .filter_map(|day| run_one(day).map_err(|e| eprintln!("{}", e)).err())
+        .count();
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{} day(s) failed", failed))
+    }
+}
+
+fn timeout_arg(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|secs| *secs > 0.0)
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .ok_or(format!(
+            "--timeout needs a number of seconds, got {}",
+            value
+        ))
+}
+
+fn run(target: &str, args: &[&str]) -> Result<(), String> {
+    let (format, rest) = take_value(args, "--format")?;
+    let format = match format {
+        Some(f) => f.parse().map_err(|e| format!("{}", e))?,
+        None => Format::Text,
+    };
+    let (jobs, rest) = take_value(&rest, "--jobs")?;
+    let jobs = jobs.map(|j| count_arg("--jobs", Some(&j))).transpose()?;
+    if jobs == Some(0) {
+        return Err(String::from("--jobs must be at least 1"));
+    }
+    let (timeout, rest) = take_value(&rest, "--timeout")?;
+    let timeout = timeout.map(timeout_arg).transpose()?;
+    let spans = rest.contains(&"--spans");
+    let no_cache = rest.contains(&"--no-cache");
+    let rest: Vec<&str> = rest
+        .iter()
+        .copied()
+        .filter(|a| !["--spans", "--no-cache"].contains(a))
+        .collect();
+    if spans && matches!(format, Format::Json | Format::Csv) {
+        return Err(String::from(
+            "--spans only works with text and table output",
+        ));
+    }
+    let options = RunOptions::parse(&rest)?;
+
+    let days = select_days(target, &options)?;
+    // Parallel days would draw over each other's progress lines.
+    let limits = Limits {
+        timeout,
+        show_progress: jobs.is_none() && io::stderr().is_terminal(),
+    };
+    progress::catch_interrupt();
+    // Cached records have no spans to show.
+    let cache = (!no_cache && !spans).then(|| ResultCache::new(cache_dir()));
+    let run_one = |day: &&'static Day| {
+        let input = options.input_for(day.number);
+        match &cache {
+            Some(cache) => runner::run_day_cached(day, &input, &options.parts, limits, cache),
+            None => runner::run_day(day, &input, &options.parts, limits),
+        }
+    };
+
+    let start = Instant::now();
+    let records: Vec<Record> = match jobs {
+        Some(jobs) => runner::parallel_map(&days, jobs, run_one).concat(),
+        // Text is printed as each day finishes, the other formats need all records at once.
+        None => days
+            .iter()
+            .flat_map(|day| {
+                let records = run_one(day);
+                if format == Format::Text {
+                    print!("{}", format.render(&records));
+                }
+                records
+            })
+            .collect(),
+    };
+    let wall_time = start.elapsed();
+
+    match (format, jobs) {
+        (Format::Text, None) => {}
+        // A parallel run has no sensible streaming order, so text becomes a summary table.
+        (Format::Text | Format::Table, _) => {
+            print!("{}", Format::Table.render(&records));
+            println!("{}", total_line(&records, wall_time, jobs.unwrap_or(1)));
+        }
+        (format, _) => print!("{}", format.render(&records)),
+    }
+    if spans {
+        print!("\n{}", timing_tree(&records));
+    }
+
+    let failed = records.iter().filter(|r| !r.status.is_ok()).count();
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{} part(s) failed", n)),
+    }
+}
+
+fn total_line(records: &[Record], wall_time: Duration, jobs: usize) -> String {
+    // Parse time is shared by the parts of a day, count it once. Cached parts were timed by an
+    // earlier run, so they add nothing to the time spent by this one.
+    let mut days = Vec::new();
+    let mut parsed_days = Vec::new();
+    let mut cached_parts = 0;
+    let mut busy_time = Duration::ZERO;
+    for r in records {
+        if !days.contains(&r.day) {
+            days.push(r.day);
+        }
+        if r.cached {
+            cached_parts += 1;
+            continue;
+        }
+        if !parsed_days.contains(&r.day) {
+            parsed_days.push(r.day);
+            busy_time += r.parse_time.unwrap_or_default();
+        }
+        busy_time += r.solve_time.unwrap_or_default();
+    }
+    let mut line = format!(
+        "Total: {:.1?} for {} day(s) on {} job(s), {:.1?} spent parsing and solving",
+        wall_time,
+        days.len(),
+        jobs,
+        busy_time
+    );
+    if cached_parts > 0 {
+        line += &format!(", {} part(s) answered from the cache", cached_parts);
+    }
+    line
+}
+
+fn run_bench(target: &str, args: &[&str]) -> Result<(), String> {
+    let (bench_options, rest) = BenchOptions::parse(args)?;
+    let options = RunOptions::parse(&rest)?;
+    println!("Build profile: {}", build_profile());
+    progress::catch_interrupt();
+    if !bench_options.compare {
+        let open = |name: &Option<String>, existing| {
+            name.as_deref()
+                .map(|n| Baseline::open(n, existing))
+                .transpose()
+                .map_err(|e| e.to_string())
+        };
+        let baselines = Baselines {
+            save: RefCell::new(open(&bench_options.save_baseline, false)?),
+            compare: open(&bench_options.baseline, true)?,
+            threshold: bench_options.threshold,
+            regressions: Cell::new(0),
+        };
+        if let Some(profile) = baselines.compare.as_ref().and_then(Baseline::profile) {
+            if profile != build_profile() {
+                eprintln!(
+                    "Warning: the baseline was taken with a {} build, this is a {} build",
+                    profile,
+                    build_profile()
+                );
+            }
+        }
+
+        let benched = for_days(target, &options, |day| {
+            bench_day(day, &options, &bench_options, &baselines)
+        });
+        // Days that did get benched are saved even when others failed.
+        if let Some(save) = baselines.save.into_inner() {
+            save.save().map_err(|e| e.to_string())?;
+            println!("Saved baseline {}", save.name());
+        }
+        benched?;
+        return match baselines.regressions.get() {
+            0 => Ok(()),
+            n => Err(format!(
+                "{} regression(s) against baseline {}",
+                n,
+                bench_options.baseline.unwrap_or_default()
+            )),
+        };
+    }
+
+    let disagreements = Cell::new(0);
+    for_days(target, &options, |day| {
+        compare_day(day, &options, &bench_options, &disagreements)
+    })?;
+    match disagreements.get() {
+        0 => Ok(()),
+        n => Err(format!(
+            "{} implementation(s) disagree with the reference",
+            n
+        )),
+    }
+}
+
+fn run_check(target: &str, args: &[&str]) -> Result<(), String> {
+    let record_all = args.contains(&"--record");
+    let rest: Vec<&str> = args.iter().copied().filter(|a| *a != "--record").collect();
+    let options = RunOptions::parse(&rest)?;
+    if options.input == Some(InputSource::Stdin) {
+        return Err(String::from(
+            "check needs an input file, stdin has no stored answers",
+        ));
+    }
+
+    let mismatches = Cell::new(0);
+    let missing = RefCell::new(Vec::new());
+    let result = for_days(target, &options, |day| {
+        check_day(day, &options, &mismatches, &missing)
+    });
+
+    let missing = missing.into_inner();
+    if !missing.is_empty() {
+        let question = format!("Record {} missing answer(s)?", missing.len());
+        if record_all || confirm(&question) {
+            record(&missing).map_err(|e| format!("Cannot record answers: {}", e))?;
+        }
+    }
+
+    result?;
+    match mismatches.get() {
+        0 => Ok(()),
+        n => Err(format!("{} answer(s) did not match", n)),
+    }
+}
+
+fn run_fetch(target: &str) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let days: Vec<u8> = match target {
+        "all" => days::DAYS.iter().map(|day| day.number).collect(),
+        // Unsolved days are fine, their input is needed to start on them.
+        day => vec![parse_day(day)?],
+    };
+
+    let mut failed = 0;
+    for day in days {
+        match fetch_input(&config, day) {
+            Ok(Fetched::Cached(path)) => println!("Day {:02}: cached {}", day, path.display()),
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {:02}: downloaded {}", day, path.display())
+            }
+            Err(e) => {
+                eprintln!("{}", day_error(day, &InputSource::for_day(day), e));
+                failed += 1;
+            }
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{} day(s) failed", n)),
+    }
+}
+
+fn run_submit(day: &str, part: &str) -> Result<(), String> {
+    let config = Config::load().map_err(|e| e.to_string())?;
+    let day = select_days(day, &RunOptions::parse(&[])?)?[0];
+    let part: Part = part.parse().map_err(|e| format!("{}", e))?;
+    let source = InputSource::for_day(day.number);
+    let fail = |e: AocError| day_error(day.number, &source, e);
+    let mut answers = Answers::load(&source).map_err(fail)?;
+
+    let limits = Limits {
+        timeout: None,
+        show_progress: io::stderr().is_terminal(),
+    };
+    progress::catch_interrupt();
+    let record = runner::run_day(day, &source, &[part], limits).remove(0);
+    if !record.status.is_ok() {
+        let status = format!("{}: {}", record.status, record.status.message());
+        return Err(day_error(day.number, &source, status));
+    }
+    if record.answer == Unsolved.to_string() {
+        return Err(format!("Day {:02} {} is not solved yet", day.number, part));
+    }
+
+    println!("Day {:02} {}: {}", day.number, part, record.answer);
+    let submission =
+        submit_answer(&config, &mut answers, day.number, part, &record.answer).map_err(fail)?;
+    if submission.recorded {
+        println!("Not sent, already known: {}", submission.verdict);
+    } else {
+        println!("{}", submission.verdict);
+        answers.save().map_err(fail)?;
+    }
+
+    match submission.verdict {
+        Verdict::Right => Ok(()),
+        verdict => Err(format!("answer not accepted: {}", verdict)),
+    }
+}
+
+fn run_leaderboard(source: &str, args: &[&str]) -> Result<(), String> {
+    let (timeout, rest) = take_value(args, "--timeout")?;
+    let timeout = timeout.map_or(Ok(Duration::from_secs(5)), timeout_arg)?;
+    if let Some(arg) = rest.first() {
+        return Err(format!("Unknown option: {}", arg));
+    }
+
+    let text = match (Path::new(source).is_file(), source.parse()) {
+        (false, Ok(id)) => {
+            let config = Config::load().map_err(|e| e.to_string())?;
+            fetch_leaderboard(&config, id).map_err(|e| e.to_string())?
+        }
+        _ => fs::read_to_string(source).map_err(|e| format!("{}: {}", source, e))?,
+    };
+    let leaderboard = Leaderboard::parse(&text).map_err(|e| format!("{}: {}", source, e))?;
+
+    let limits = Limits {
+        timeout: Some(timeout),
+        show_progress: io::stderr().is_terminal(),
+    };
+    progress::catch_interrupt();
+    // Cached answers come with the times of the run that was cached, which is all the headings show.
+    let cache = ResultCache::new(cache_dir());
+    let ours: Vec<Record> = (1..=leaderboard.last_day())
+        .filter_map(days::find)
+        .filter(|day| InputSource::for_day(day.number).exists())
+        .flat_map(|day| {
+            let input = InputSource::for_day(day.number);
+            runner::run_day_cached(day, &input, &Part::ALL, limits, &cache)
+        })
+        .collect();
+
+    print!("{}", leaderboard.render(&ours));
+    Ok(())
+}
+
+fn run_examples(day: &str, page: &str, args: &[&str]) -> Result<(), String> {
+    let force = match args {
+        [] => false,
+        ["--force"] => true,
+        [arg, ..] => return Err(format!("Unknown option: {}", arg)),
+    };
+    let day = parse_day(day)?;
+    let html = fs::read_to_string(page).map_err(|e| format!("{}: {}", page, e))?;
+
+    let examples = extract_examples(&html).map_err(|e| format!("{}: {}", page, e))?;
+    if examples.is_empty() {
+        return Err(format!("{}: no example found", page));
+    }
+    for example in &examples {
+        let answers: Vec<String> = example
+            .answers
+            .iter()
+            .map(|(part, answer)| format!("{} = {}", part, answer))
+            .collect();
+        let summary = format!(
+            "{} lines, {}",
+            example.input.lines().count(),
+            match answers.is_empty() {
+                true => String::from("no answers"),
+                false => answers.join(", "),
+            }
+        );
+        match save_example(day, example, force).map_err(|e| e.to_string())? {
+            Saved::Written(path) => println!("{}: written ({})", path.display(), summary),
+            Saved::Unchanged(path) => println!("{}: unchanged ({})", path.display(), summary),
+            Saved::Kept(path) => println!(
+                "{}: differs, kept the existing one, use --force to replace it ({})",
+                path.display(),
+                summary
+            ),
+        }
+    }
+    Ok(())
+}
+
+fn run_new(day: &str) -> Result<(), String> {
+    let day = parse_day(day)?;
+    // The sources this binary was built from.
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let solution = solution_path(root, day);
+    if days::find(day).is_some() || solution.exists() {
+        return Err(format!(
+            "Day {:02} already exists in {}",
+            day,
+            solution.display()
+        ));
+    }
+
+    for path in new_day(root, day).map_err(|e| e.to_string())? {
+        println!("{}", path.display());
+    }
+    println!(
+        "Rebuild to run it: cargo run --release --bin aoc -- run {}",
+        day
+    );
+    Ok(())
+}
+
+fn run_watch(day: &str, args: &[&str]) -> Result<(), String> {
+    let day = parse_day(day)?;
+    let options = RunOptions::parse(args)?;
+    let InputSource::File(input) = options.input_for(day) else {
+        return Err(String::from(
+            "watch cannot read stdin, the input is read again on every run",
+        ));
+    };
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let watched = [solution_path(root, day), input];
+    let exe = env::current_exe().map_err(|e| format!("Cannot find the aoc binary: {}", e))?;
+
+    let mut build = process::Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    build
+        .current_dir(root)
+        .args(["build", "--quiet", "--bin", "aoc"]);
+    if build_profile() == "release" {
+        build.arg("--release");
+    }
+    if cfg!(feature = "count-allocs") {
+        build.args(["--features", "count-allocs"]);
+    }
+
+    let names: Vec<String> = watched.iter().map(|p| p.display().to_string()).collect();
+    println!("Watching {} (Ctrl-C to stop)\n", names.join(", "));
+    let modified = || {
+        watched
+            .each_ref()
+            .map(|p| fs::metadata(p).and_then(|m| m.modified()).ok())
+    };
+    let mut seen = None;
+    let mut previous: Vec<Record> = Vec::new();
+    loop {
+        let stamps = modified();
+        if seen == Some(stamps) {
+            thread::sleep(Duration::from_millis(500));
+            continue;
+        }
+        if let Some(before) = seen {
+            let changed: Vec<&str> = names
+                .iter()
+                .zip(before.iter().zip(&stamps))
+                .filter(|(_, (a, b))| a != b)
+                .map(|(name, _)| name.as_str())
+                .collect();
+            println!("\n{} changed", changed.join(", "));
+        }
+        seen = Some(stamps);
+        let built = build
+            .status()
+            .map_err(|e| format!("Cannot run cargo: {}", e))?;
+        if !built.success() {
+            println!("Build failed, waiting for changes");
+            continue;
+        }
+        // The freshly built binary does the run, so the solution is the one just saved.
+        let output = process::Command::new(&exe)
+            .args(["run", &day.to_string()])
+            .args(args)
+            .args(["--format", "csv", "--no-cache"])
+            .output()
+            .map_err(|e| format!("Cannot run {}: {}", exe.display(), e))?;
+        match Record::parse_csv(&String::from_utf8_lossy(&output.stdout)) {
+            Ok(records) if !records.is_empty() => {
+                print!("{}", compare_runs(&previous, &records));
+                previous = records;
+            }
+            // Nothing was run, e.g. the input is missing: the error says why.
+            _ => print!("{}", String::from_utf8_lossy(&output.stderr)),
+        }
+    }
+}
+
+fn run_generate(target: &str, args: &[&str]) -> Result<(), String> {
+    let (mut seed, mut size, mut save) = (1, None, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match *arg {
+            "--seed" => seed = value()?.parse().map_err(|_| format!("Invalid {}", arg))?,
+            "--size" => size = Some(value()?.parse().map_err(|_| format!("Invalid {}", arg))?),
+            "--save" => save = Some(*value()?),
+            other => return Err(format!("Unknown option: {}\n\n{}", other, USAGE)),
+        }
+    }
+    let generators = match target {
+        "all" if save.is_none() => {
+            return Err(String::from(
+                "generate all needs --save, to write a file per day",
+            ))
+        }
+        "all" => days::DAYS
+            .iter()
+            .filter_map(|day| generator(day.number))
+            .collect(),
+        day => match day.parse().ok().and_then(generator) {
+            Some(generator) => vec![generator],
+            None => return Err(format!("No generator for day {}", day)),
+        },
+    };
+
+    for generator in generators {
+        let size = size.unwrap_or(generator.default_size);
+        let input = generator.generate(seed, size);
+        let Some(name) = save else {
+            print!("{}", input);
+            continue;
+        };
+        let path = example_path(generator.day, name);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(&path, input).map_err(|e| format!("{}: {}", path.display(), e))?;
+        println!(
+            "Day {:02}: {} ({} {}, seed {})",
+            generator.day,
+            path.display(),
+            size.clamp(generator.sizes.0, generator.sizes.1),
+            generator.size_unit,
+            seed
+        );
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+        ["run", target, ref rest @ ..] => run(target, rest),
+        ["bench", target, ref rest @ ..] => run_bench(target, rest),
+        ["check", target, ref rest @ ..] => run_check(target, rest),
+        ["fetch", target] => run_fetch(target),
+        ["submit", day, part] => run_submit(day, part),
+        ["leaderboard", source, ref rest @ ..] => run_leaderboard(source, rest),
+        ["examples", day, page, ref rest @ ..] => run_examples(day, page, rest),
+        ["new", day] => run_new(day),
+        ["watch", day, ref rest @ ..] => run_watch(day, rest),
+        ["generate", target, ref rest @ ..] => run_generate(target, rest),
+        _ => Err(String::from(USAGE)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::days::Day;
+use crate::{AocError, Format, InputSource, Part, Record};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Environment variable that overrides where results are cached.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// What every solution builds on besides its own source file, listed by `build.rs`. A change to any
+/// of these invalidates all cached results.
+const SHARED_SOURCES: &[&str] = &include!(concat!(env!("OUT_DIR"), "/shared_sources.rs"));
+
+/// The directory results are cached in: `$AOC_CACHE_DIR`, or `./.aoc-cache` when that is unset.
+pub fn cache_dir() -> PathBuf {
+    env::var_os(CACHE_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("./.aoc-cache"))
+}
+
+/// Answers of earlier runs, one file per input, keyed on the input and the code that solved it.
+/// Inputs of the same name in different directories, e.g. two `$AOC_INPUT_DIR`s, are cached apart.
+pub struct ResultCache {
+    dir: PathBuf,
+}
+
+impl ResultCache {
+    pub fn new(dir: PathBuf) -> ResultCache {
+        ResultCache { dir }
+    }
+
+    /// The key of running `day` on `source`: a hash of the input and the solution's sources.
+    /// `None` for stdin, which cannot be read twice, and for inputs that cannot be read.
+    pub fn key(&self, day: &Day, source: &InputSource) -> Option<u64> {
+        let InputSource::File(path) = source else {
+            return None;
+        };
+        let input = fs::read(path).ok()?;
+        let hash = SHARED_SOURCES
+            .iter()
+            .chain([&day.source()])
+            .fold(FNV_OFFSET, |hash, s| fnv1a(hash, s.as_bytes()));
+        Some(fnv1a(hash, &input))
+    }
+
+    /// The cached records of `parts`, when all of them are cached under `key`.
+    pub fn lookup(&self, key: u64, source: &InputSource, parts: &[Part]) -> Option<Vec<Record>> {
+        let text = fs::read_to_string(self.path(key, source)).ok()?;
+        let cached = Record::parse_csv(&text).ok()?;
+        parts
+            .iter()
+            .map(|part| {
+                let mut record = cached.iter().find(|r| r.part == *part)?.clone();
+                record.cached = true;
+                Some(record)
+            })
+            .collect()
+    }
+
+    /// Caches the records that have an answer under `key`, next to parts cached before, and drops the
+    /// results of earlier versions of the input or solution.
+    pub fn store(
+        &self,
+        key: u64,
+        source: &InputSource,
+        records: &[Record],
+    ) -> Result<(), AocError> {
+        let path = self.path(key, source);
+        let mut kept: Vec<Record> = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| Record::parse_csv(&text).ok())
+            .unwrap_or_default();
+        kept.retain(|old| {
+            !records
+                .iter()
+                .any(|r| r.part == old.part && r.status.is_ok())
+        });
+        kept.extend(
+            records
+                .iter()
+                .filter(|r| r.status.is_ok() && !r.cached)
+                .cloned(),
+        );
+        if kept.is_empty() {
+            return Ok(());
+        }
+        kept.sort_by_key(|r| r.part.number());
+
+        fs::create_dir_all(&self.dir)?;
+        let prefix = file_prefix(source);
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            if name.to_string_lossy().starts_with(&prefix) && entry.path() != path {
+                fs::remove_file(entry.path())?;
+            }
+        }
+        fs::write(path, Format::Csv.render(&kept))?;
+        Ok(())
+    }
+
+    fn path(&self, key: u64, source: &InputSource) -> PathBuf {
+        self.dir
+            .join(format!("{}{:016x}.csv", file_prefix(source), key))
+    }
+}
+
+// The start of the names of every cache file of `source`.
+fn file_prefix(source: &InputSource) -> String {
+    format!("{}-", source.unique_id())
+}
+
+pub(crate) const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+// 64-bit FNV-1a, continuing from `hash`.
+pub(crate) fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::report::Status;
+    use crate::test_support::TempDir;
+    use std::path::Path;
+
+    fn records(answers: [&str; 2]) -> Vec<Record> {
+        let mut records = Record::failed(1, &Part::ALL, "day01", Status::Ok);
+        for (record, answer) in records.iter_mut().zip(answers) {
+            record.answer = String::from(answer);
+        }
+        records
+    }
+
+    fn answers(records: &[Record]) -> Vec<&str> {
+        records.iter().map(|r| r.answer.as_str()).collect()
+    }
+
+    fn cache_files(dir: &Path) -> usize {
+        fs::read_dir(dir).map_or(0, |entries| entries.count())
+    }
+
+    #[test]
+    fn finds_what_it_stored() {
+        let dir = TempDir::new("cache");
+        let cache = ResultCache::new(dir.path().join("cache"));
+        let input = dir.path().join("day01");
+        fs::write(&input, "1abc2\n").unwrap();
+        let source = InputSource::File(input);
+        let day = days::find(1).unwrap();
+
+        let key = cache.key(day, &source).unwrap();
+        assert_eq!(cache.lookup(key, &source, &Part::ALL), None);
+        cache.store(key, &source, &records(["12", "12"])).unwrap();
+
+        let cached = cache.lookup(key, &source, &Part::ALL).unwrap();
+        assert_eq!(answers(&cached), ["12", "12"]);
+        assert!(cached.iter().all(|r| r.cached));
+        assert_eq!(cache.key(day, &source), Some(key));
+    }
+
+    #[test]
+    fn keeps_only_failed_parts_out() {
+        let dir = TempDir::new("cache");
+        let cache = ResultCache::new(dir.path().join("cache"));
+        let input = dir.path().join("day01");
+        fs::write(&input, "1abc2\n").unwrap();
+        let source = InputSource::File(input);
+        let key = cache.key(days::find(1).unwrap(), &source).unwrap();
+
+        let mut run = records(["12", ""]);
+        run[1].status = Status::Panic(String::from("boom"));
+        cache.store(key, &source, &run).unwrap();
+        assert_eq!(cache.lookup(key, &source, &Part::ALL), None);
+        let part1 = cache.lookup(key, &source, &[Part::One]).unwrap();
+        assert_eq!(answers(&part1), ["12"]);
+    }
+
+    #[test]
+    fn a_changed_input_misses_and_replaces_the_old_entry() {
+        let dir = TempDir::new("cache");
+        let cache_path = dir.path().join("cache");
+        let cache = ResultCache::new(cache_path.clone());
+        let input = dir.path().join("day01");
+        fs::write(&input, "1abc2\n").unwrap();
+        let source = InputSource::File(input.clone());
+        let day = days::find(1).unwrap();
+
+        let old = cache.key(day, &source).unwrap();
+        cache.store(old, &source, &records(["12", "12"])).unwrap();
+        fs::write(&input, "pqr3stu8vwx\n").unwrap();
+        let new = cache.key(day, &source).unwrap();
+        assert_ne!(old, new);
+        assert_eq!(cache.lookup(new, &source, &Part::ALL), None);
+
+        cache.store(new, &source, &records(["38", "38"])).unwrap();
+        assert_eq!(cache.lookup(old, &source, &Part::ALL), None);
+        assert_eq!(cache_files(&cache_path), 1);
+    }
+
+    #[test]
+    fn inputs_of_the_same_name_in_other_directories_are_apart() {
+        let dir = TempDir::new("cache");
+        let cache_path = dir.path().join("cache");
+        let cache = ResultCache::new(cache_path.clone());
+        let day = days::find(1).unwrap();
+        let source = |name: &str, input: &str| {
+            let inputs = dir.path().join(name);
+            fs::create_dir_all(&inputs).unwrap();
+            fs::write(inputs.join("day01"), input).unwrap();
+            InputSource::File(inputs.join("day01"))
+        };
+        let a = source("a", "1abc2\n");
+        let b = source("b", "pqr3stu8vwx\n");
+
+        let key_a = cache.key(day, &a).unwrap();
+        cache.store(key_a, &a, &records(["12", "12"])).unwrap();
+        let key_b = cache.key(day, &b).unwrap();
+        cache.store(key_b, &b, &records(["38", "38"])).unwrap();
+
+        assert_eq!(cache_files(&cache_path), 2);
+        let cached_a = cache.lookup(key_a, &a, &Part::ALL).unwrap();
+        assert_eq!(answers(&cached_a), ["12", "12"]);
+        let cached_b = cache.lookup(key_b, &b, &Part::ALL).unwrap();
+        assert_eq!(answers(&cached_b), ["38", "38"]);
+    }
+
+    #[test]
+    fn stdin_is_never_cached() {
+        let dir = TempDir::new("cache");
+        let cache = ResultCache::new(dir.path().to_path_buf());
+        assert_eq!(cache.key(days::find(1).unwrap(), &InputSource::Stdin), None);
+    }
+}
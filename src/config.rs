@@ -0,0 +1,89 @@
+use crate::toml;
+use crate::AocError;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Where the config file is read from, overriding `./aoc.toml`.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+/// The session token, overriding the one in the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// How to reach the puzzle server, read from `aoc.toml`:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// year = 2023
+/// user_agent = "github.com/jdijt/adventofcode2023 by someone@example.com"
+/// ```
+///
+/// Everything but the session token has a default. Keep the file out of version control.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+    pub user_agent: String,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            base_url: String::from("https://adventofcode.com"),
+            year: 2023,
+            user_agent: String::from("github.com/jdijt/adventofcode2023"),
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        env::var_os(CONFIG_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("aoc.toml"))
+    }
+
+    pub fn load() -> Result<Config, AocError> {
+        let path = Config::path();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let in_file = |msg: String| AocError::Config(format!("{} (in {})", msg, path.display()));
+
+        let mut config = Config::default();
+        for (key, value) in toml::parse(&text).map_err(|e| in_file(e.to_string()))? {
+            match key.as_str() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                "year" => {
+                    config.year = value
+                        .parse()
+                        .map_err(|_| in_file(format!("invalid year {:?}", value)))?
+                }
+                "user_agent" => config.user_agent = value,
+                other => return Err(in_file(format!("unknown key {:?}", other))),
+            }
+        }
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        config.base_url = String::from(config.base_url.trim_end_matches('/'));
+        Ok(config)
+    }
+
+    /// The session token, which every request needs.
+    pub fn session(&self) -> Result<&str, AocError> {
+        self.session.as_deref().ok_or_else(|| {
+            AocError::Config(format!(
+                "no session token, set session in {} or ${}",
+                Config::path().display(),
+                SESSION_VAR
+            ))
+        })
+    }
+}
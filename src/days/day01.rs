@@ -0,0 +1,105 @@
+use crate::{parse_lines, AocError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn find_first<'a>(s: &str, search_for: &[&'a str]) -> Option<&'a str> {
+    let (_, res) = search_for
+        .iter()
+        .flat_map(|opt| s.find(opt).map(|idx| (idx, opt)))
+        .min_by_key(|t| t.0)?;
+
+    Some(res)
+}
+
+fn find_last<'a>(s: &str, search_for: &[&'a str]) -> Option<&'a str> {
+    let (_, res) = search_for
+        .iter()
+        .flat_map(|opt| s.rfind(opt).map(|idx| (idx, opt)))
+        .max_by_key(|t| t.0)?;
+
+    Some(res)
+}
+
+fn part1(lines: &[String]) -> i32 {
+    let search_for = Vec::from(["1", "2", "3", "4", "5", "6", "7", "8", "9"]);
+
+    // Lines with spelled out digits only have no value in part 1.
+    lines
+        .iter()
+        .filter_map(|line| {
+            let val = format!(
+                "{}{}",
+                find_first(line, &search_for)?,
+                find_last(line, &search_for)?
+            );
+            Some(val.parse::<i32>().unwrap())
+        })
+        .sum()
+}
+
+fn part2(lines: &[String]) -> i32 {
+    let numerics = HashMap::from([
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+    ]);
+    let search_for: Vec<&str> = numerics.keys().copied().collect();
+
+    lines
+        .iter()
+        .map(|line| {
+            // Parsing made sure every line has a digit, written or spelled out.
+            let first = numerics[find_first(line, &search_for).unwrap()];
+            let last = numerics[find_last(line, &search_for).unwrap()];
+            format!("{}{}", first, last).parse::<i32>().unwrap()
+        })
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        // Part 1 only reads written digits, but part 2's lines may have spelled out ones only.
+        parse_lines(lines, |l| {
+            if l.contains('0') {
+                Err(AocError::parse("0 is not a calibration digit"))
+            } else if l.contains(|c: char| c.is_ascii_digit())
+                || DIGIT_WORDS.iter().any(|w| l.contains(w))
+            {
+                Ok(String::from(l))
+            } else {
+                Err(AocError::parse("line contains no digit"))
+            }
+        })
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
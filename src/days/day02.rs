@@ -1,7 +1,8 @@
-use aoc2023::{read_lines, run_timed};
+use crate::{parse_lines, parse_num, AocError, Solution};
 use std::cmp;
+use std::fmt::Display;
 
-struct Game {
+pub struct Game {
     id: i32,
     min_red: i32,
     min_green: i32,
@@ -32,33 +33,38 @@ impl Game {
     }
 }
 
-fn parse_line(l: &str) -> Game {
-    let (game_header, revelations) = l.split_once(":").unwrap();
+fn parse_line(l: &str) -> Result<Game, AocError> {
+    let (game_header, revelations) = l
+        .split_once(":")
+        .ok_or_else(|| AocError::parse("missing ':' after game header"))?;
     let game_id = game_header
         .split_once(" ")
-        .map(|(_, num)| num.trim().parse::<i32>().unwrap())
-        .unwrap();
+        .map(|(_, num)| parse_num(num))
+        .ok_or_else(|| AocError::parse(format!("invalid game header {:?}", game_header)))??;
 
     let mut game = Game::new(game_id);
 
     for revelation in revelations.split(';') {
         for count in revelation.trim().split(',') {
-            let (c_s, color) = count.trim().split_once(" ").unwrap();
-            let c_i = c_s.parse::<i32>().unwrap();
+            let (c_s, color) = count
+                .trim()
+                .split_once(" ")
+                .ok_or_else(|| AocError::parse(format!("invalid cube count {:?}", count)))?;
+            let c_i = parse_num(c_s)?;
 
             match color {
                 "blue" => game.add_blue(c_i),
                 "green" => game.add_green(c_i),
                 "red" => game.add_red(c_i),
-                _other => panic!("Unknown color {}", color),
+                _other => return Err(AocError::parse(format!("unknown color {:?}", color))),
             }
         }
     }
 
-    return game;
+    Ok(game)
 }
 
-fn part1(games: &Vec<Game>) -> i32 {
+fn part1(games: &[Game]) -> i32 {
     games
         .iter()
         .filter(|g| g.min_blue <= 14 && g.min_green <= 13 && g.min_red <= 12)
@@ -66,15 +72,24 @@ fn part1(games: &Vec<Game>) -> i32 {
         .sum()
 }
 
-fn part2(games: &Vec<Game>) -> i32 {
+fn part2(games: &[Game]) -> i32 {
     games.iter().map(|g| g.power()).sum()
 }
 
-fn main() {
-    let games = read_lines("./inputs/day02")
-        .map(|l| parse_line(&l))
-        .collect();
+pub struct Day02;
 
-    println!("Part 1: {}", run_timed(|| part1(&games)));
-    println!("Part 2: {}", run_timed(|| part2(&games)));
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        parse_lines(lines, parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
 }
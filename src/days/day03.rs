@@ -0,0 +1,103 @@
+use crate::geometry::UPoint;
+use crate::{AocError, Grid, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub type Schematic = Grid<char>;
+
+// Row-major positions, with an extra position past the end of every row so numbers never run onto the next line.
+fn scan(schematic: &Schematic) -> impl Iterator<Item = UPoint> {
+    let width = schematic.width();
+    (0..schematic.height()).flat_map(move |y| (0..=width).map(move |x| UPoint::new(x, y)))
+}
+
+fn part1(schematic: &Schematic) -> i64 {
+    let mut number: Vec<char> = Vec::new();
+    let mut number_adjacent = false;
+    let mut sum: i64 = 0;
+
+    for pos in scan(schematic) {
+        match schematic.get(pos) {
+            Some(value @ '0'..='9') => {
+                number.push(*value);
+                for neighbour in schematic.neighbours8(pos) {
+                    let char = &schematic[neighbour];
+                    if !(char.is_ascii_digit() || *char == '.') {
+                        number_adjacent = true;
+                    }
+                }
+            }
+            _ => {
+                if !number.is_empty() {
+                    if number_adjacent {
+                        let parsed_num: i64 = String::from_iter(number).parse().unwrap();
+                        sum += parsed_num;
+                    }
+                    number = Vec::new();
+                    number_adjacent = false;
+                }
+            }
+        }
+    }
+    sum
+}
+
+fn part2(schematic: &Schematic) -> u64 {
+    let mut gear_candidates: HashMap<UPoint, Vec<u64>> = HashMap::new();
+
+    let mut number: Vec<char> = Vec::new();
+    let mut number_gear: Option<UPoint> = None;
+
+    for pos in scan(schematic) {
+        match schematic.get(pos) {
+            Some(value @ '0'..='9') => {
+                number.push(*value);
+                for neighbour in schematic.neighbours8(pos) {
+                    let char = &schematic[neighbour];
+                    if *char == '*' {
+                        number_gear = Some(neighbour);
+                    }
+                }
+            }
+            _ => {
+                if !number.is_empty() {
+                    if let Some(gear) = number_gear {
+                        let parsed_num: u64 = String::from_iter(number).parse().unwrap();
+                        gear_candidates.entry(gear).or_default().push(parsed_num);
+                    }
+                    number = Vec::new();
+                    number_gear = None;
+                }
+            }
+        }
+    }
+
+    gear_candidates
+        .values()
+        .map(|vs| {
+            if vs.len() == 2 {
+                vs.iter().product()
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        Grid::parse(lines, Ok)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{parse_lines, parse_num, AocError, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::ops::AddAssign;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Card {
+    id: usize,
+    winning_number_count: usize,
+    score: usize,
+}
+
+impl FromStr for Card {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Card, AocError> {
+        let (card_identifier, numbers) = line
+            .split_once(":")
+            .ok_or_else(|| AocError::parse("missing ':' after card header"))?;
+        let id = card_identifier
+            .strip_prefix("Card")
+            .ok_or_else(|| AocError::parse(format!("invalid card header {:?}", card_identifier)))
+            .and_then(parse_num)?;
+
+        fn split_to_numbers<C>(line: &str) -> Result<C, AocError>
+        where
+            C: FromIterator<i32>,
+        {
+            line.split_whitespace().map(parse_num).collect()
+        }
+
+        let (wn_line, n_line) = numbers
+            .trim()
+            .split_once("|")
+            .ok_or_else(|| AocError::parse("missing '|' between number lists"))?;
+        let winning_numbers: HashSet<i32> = split_to_numbers(wn_line)?;
+        let numbers: Vec<i32> = split_to_numbers(n_line)?;
+
+        let winning_number_count = numbers
+            .iter()
+            .filter(|n| winning_numbers.contains(n))
+            .count();
+
+        let score = if winning_number_count >= 1 {
+            1 << (winning_number_count - 1)
+        } else {
+            0
+        };
+
+        Ok(Card {
+            id,
+            winning_number_count,
+            score,
+        })
+    }
+}
+
+fn part2(cards: &Vec<Card>) -> u32 {
+    let mut additional_cards: Vec<u32> = Vec::with_capacity(cards.len());
+    for card in cards {
+        additional_cards.insert(card.id - 1, 1);
+    }
+
+    for card in cards {
+        let id = card.id;
+        let count = *additional_cards.get(id - 1).unwrap();
+        let card_score = card.winning_number_count;
+        for idx in id..(id + card_score) {
+            additional_cards.get_mut(idx).unwrap().add_assign(count);
+        }
+    }
+
+    additional_cards.iter().sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        let cards: Vec<Card> = parse_lines(lines, |l| l.trim().parse())?;
+
+        // Part 2 looks cards up by id, and copies never run past the end of the table.
+        for (idx, card) in cards.iter().enumerate() {
+            if card.id != idx + 1 {
+                return Err(AocError::invalid(format!(
+                    "expected card {}, found card {}",
+                    idx + 1,
+                    card.id
+                )));
+            }
+            if card.id + card.winning_number_count > cards.len() {
+                return Err(AocError::invalid(format!(
+                    "card {} wins copies past the last card",
+                    card.id
+                )));
+            }
+        }
+
+        Ok(cards)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.iter().map(|c| c.score).sum::<usize>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{parse_num, AocError, Part, Solution, Unsolved, Variant};
+use std::fmt::Display;
+use std::ops::Range;
+use std::str::FromStr;
+use std::thread;
+use std::thread::JoinHandle;
+
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    applies_to: Range<i64>,
+    offset: i64,
+}
+
+impl FromStr for Mapping {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Mapping, AocError> {
+        let parts: Vec<i64> = line
+            .split_whitespace()
+            .map(parse_num)
+            .collect::<Result<_, _>>()?;
+
+        if let [dest_start, src_start, len] = parts.as_slice() {
+            Ok(Mapping {
+                applies_to: *src_start..(src_start + len),
+                offset: dest_start - src_start,
+            })
+        } else {
+            Err(AocError::parse(format!(
+                "expected 3 numbers in a mapping, found {}",
+                parts.len()
+            )))
+        }
+    }
+}
+
+impl Mapping {
+    fn apply_to(&self, value: i64) -> i64 {
+        if self.applies_to.contains(&value) {
+            value + self.offset
+        } else {
+            value
+        }
+    }
+
+    fn mutate(&self, value: &mut i64) {
+        if self.applies_to.contains(value) {
+            *value += self.offset
+        }
+    }
+}
+
+fn parse_file(
+    mut lines: impl Iterator<Item = String>,
+) -> Result<(Vec<i64>, Vec<Vec<Mapping>>), AocError> {
+    let seeds: Vec<i64> = lines
+        .next()
+        .ok_or_else(|| AocError::parse("input is empty"))?
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| AocError::parse("first line does not list the seeds").on_line(0))?
+        .split_whitespace()
+        .map(parse_num)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.on_line(0))?;
+    if seeds.is_empty() {
+        return Err(AocError::invalid("no seeds"));
+    }
+    // Part 2 reads the seeds as start and length pairs.
+    if !seeds.len().is_multiple_of(2) {
+        return Err(AocError::invalid(format!(
+            "{} seeds do not pair up into ranges",
+            seeds.len()
+        )));
+    }
+    if seed_ranges(&seeds).iter().all(|r| r.is_empty()) {
+        return Err(AocError::invalid("every seed range is empty"));
+    }
+
+    let mut mappings: Vec<Vec<Mapping>> = Vec::new();
+    for (idx, line) in (1..).zip(lines) {
+        if line.starts_with(|c: char| c.is_ascii_digit()) {
+            let ms = mappings
+                .last_mut()
+                .ok_or_else(|| AocError::parse("mapping before any map header").on_line(idx))?;
+            ms.push(line.parse().map_err(|e: AocError| e.on_line(idx))?);
+        } else if line.starts_with(|c: char| c.is_alphabetic()) {
+            mappings.push(Vec::new());
+        }
+    }
+
+    Ok((seeds, mappings))
+}
+
+fn part1(seeds: &[i64], mappings: &[Vec<Mapping>]) -> i64 {
+    let mapped_seeds = mappings.iter().fold(seeds.to_vec(), |acc, ms| {
+        acc.into_iter()
+            .map(|v| {
+                ms.iter()
+                    .find(|m| m.applies_to.contains(&v))
+                    .map_or(v, |m| m.apply_to(v))
+            })
+            .collect()
+    });
+
+    *mapped_seeds.iter().min().unwrap()
+}
+
+fn seed_ranges(seeds: &[i64]) -> Vec<Range<i64>> {
+    seeds
+        .chunks_exact(2)
+        .map(|chunk| {
+            let from = *chunk.first().unwrap();
+            let to = from + chunk.last().unwrap();
+            from..to
+        })
+        .collect()
+}
+
+// Expanding more seeds than this takes gigabytes of memory.
+const BRUTE_FORCE_MAX_SEEDS: i64 = 10_000_000;
+
+// Maps every seed one by one. Far too much memory for the real input, but simple enough to check
+// range splitting against on smaller ones.
+fn part2_brute_force(seeds: &[i64], mappings: &[Vec<Mapping>]) -> i64 {
+    let expanded_seeds = seed_ranges(seeds);
+
+    let threads: Vec<JoinHandle<i64>> = expanded_seeds
+        .into_iter()
+        .map(|range| {
+            let mappings_clone: Vec<Vec<Mapping>> = mappings.to_vec();
+            thread::spawn(move || {
+                let mut seeds = range.collect::<Vec<i64>>();
+                for ms in mappings_clone {
+                    for v in seeds.iter_mut() {
+                        if let Some(m) = ms.iter().find(|m| m.applies_to.contains(v)) {
+                            m.mutate(v);
+                        }
+                    }
+                }
+                *seeds.iter().min().unwrap()
+            })
+        })
+        .collect();
+
+    threads
+        .into_iter()
+        .map(|j| j.join().unwrap())
+        .min()
+        .unwrap()
+}
+
+// Maps whole ranges at once, splitting them where a mapping starts or ends.
+fn part2(seeds: &[i64], mappings: &[Vec<Mapping>]) -> i64 {
+    let mut ranges: Vec<Range<i64>> = seed_ranges(seeds)
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .collect();
+
+    for ms in mappings {
+        let mut mapped = Vec::new();
+        while let Some(range) = ranges.pop() {
+            let overlapping = ms
+                .iter()
+                .find(|m| m.applies_to.start < range.end && range.start < m.applies_to.end);
+            let Some(m) = overlapping else {
+                mapped.push(range);
+                continue;
+            };
+
+            let start = range.start.max(m.applies_to.start);
+            let end = range.end.min(m.applies_to.end);
+            mapped.push(start + m.offset..end + m.offset);
+            // The parts outside this mapping may still fall in another one.
+            if range.start < start {
+                ranges.push(range.start..start);
+            }
+            if end < range.end {
+                ranges.push(end..range.end);
+            }
+        }
+        ranges = mapped;
+    }
+
+    ranges.iter().map(|r| r.start).min().unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<i64>, Vec<Vec<Mapping>>);
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        parse_file(lines)
+    }
+
+    fn part1((seeds, mappings): &Self::Input) -> impl Display {
+        part1(seeds, mappings)
+    }
+
+    fn part2((seeds, mappings): &Self::Input) -> impl Display {
+        part2(seeds, mappings)
+    }
+
+    const VARIANTS: &'static [Variant<Self::Input>] = &[Variant {
+        name: "brute force",
+        part: Part::Two,
+        solve: |(seeds, mappings)| {
+            let count: i64 = seed_ranges(seeds).iter().map(|r| r.end - r.start).sum();
+            if count > BRUTE_FORCE_MAX_SEEDS {
+                return Unsolved.to_string();
+            }
+            part2_brute_force(seeds, mappings).to_string()
+        },
+    }];
+}
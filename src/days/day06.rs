@@ -0,0 +1,71 @@
+use crate::{parse_num, AocError, Solution};
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn win_options_count(&self) -> u64 {
+        let is_win = |press_ms: &u64| press_ms * (self.time - press_ms) > self.distance;
+
+        match (0..self.time).find(is_win) {
+            Some(first_win) => {
+                let last_win = (0..self.time).rfind(is_win).unwrap() + 1; //we want the first not winning for diff to be correct.
+                last_win - first_win
+            }
+            None => 0,
+        }
+    }
+}
+
+fn numbers(line: Option<String>, label: &str) -> Result<Vec<String>, AocError> {
+    let line = line.ok_or_else(|| AocError::parse(format!("missing {} line", label)))?;
+    let numbers = line
+        .strip_prefix(label)
+        .and_then(|l| l.strip_prefix(':'))
+        .ok_or_else(|| AocError::parse(format!("expected a line starting with {:?}", label)))?;
+    Ok(numbers.split_whitespace().map(String::from).collect())
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    // Part 1 reads the races separately, part 2 reads each line as a single number.
+    type Input = (Vec<Race>, Race);
+
+    fn parse(mut lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        let times = numbers(lines.next(), "Time").map_err(|e| e.on_line(0))?;
+        let distances = numbers(lines.next(), "Distance").map_err(|e| e.on_line(1))?;
+        if times.len() != distances.len() {
+            return Err(AocError::invalid("number of times and distances differ"));
+        }
+
+        let races = times
+            .iter()
+            .zip(distances.iter())
+            .map(|(t, d)| {
+                Ok(Race {
+                    time: parse_num(t)?,
+                    distance: parse_num(d)?,
+                })
+            })
+            .collect::<Result<_, AocError>>()?;
+        let single_race = Race {
+            time: parse_num(&times.concat())?,
+            distance: parse_num(&distances.concat())?,
+        };
+
+        Ok((races, single_race))
+    }
+
+    fn part1((races, _): &Self::Input) -> impl Display {
+        races.iter().map(|r| r.win_options_count()).product::<u64>()
+    }
+
+    fn part2((_, single_race): &Self::Input) -> impl Display {
+        single_race.win_options_count()
+    }
+}
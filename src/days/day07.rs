@@ -1,17 +1,28 @@
-use crate::HandType::{
+use self::HandType::{
     FiveOfAKind, FourOfAKind, FullHouse, HighCard, OnePair, ThreeOfAKind, TwoPair,
 };
-use aoc2023::{read_lines, run_timed};
+use crate::{parse_lines, parse_num, AocError, Solution};
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
 struct Card(char);
 
-impl Card {
-    fn from(c: char) -> Card {
-        Card(c)
+impl TryFrom<char> for Card {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Card, AocError> {
+        if "AKQJT98765432Z".contains(c) {
+            Ok(Card(c))
+        } else {
+            Err(AocError::parse(format!("invalid card {:?}", c)))
+        }
     }
+}
+
+impl Card {
     fn ordinal(&self) -> u8 {
         match self.0 {
             'A' => 14,
@@ -49,17 +60,27 @@ enum HandType {
 }
 
 #[derive(Eq, PartialEq, Debug, Clone)]
-struct Hand {
+pub struct Hand {
     kind: HandType,
     cards: Vec<Card>,
     bid: u64,
 }
 
-impl Hand {
-    fn from(l: String) -> Hand {
-        let (h, b) = l.split_once(" ").unwrap();
-        let bid = b.parse().unwrap();
-        let cards: Vec<Card> = h.chars().map(Card::from).collect();
+impl FromStr for Hand {
+    type Err = AocError;
+
+    fn from_str(l: &str) -> Result<Hand, AocError> {
+        let (h, b) = l
+            .split_once(" ")
+            .ok_or_else(|| AocError::parse("missing ' ' between hand and bid"))?;
+        let bid = parse_num(b)?;
+        let cards: Vec<Card> = h.chars().map(Card::try_from).collect::<Result<_, _>>()?;
+        if cards.len() != 5 {
+            return Err(AocError::parse(format!(
+                "hand {:?} does not hold 5 cards",
+                h
+            )));
+        }
 
         let mut card_counts: HashMap<Card, i32> = HashMap::new();
         for c in cards.iter() {
@@ -86,10 +107,10 @@ impl Hand {
             ([2, 2, 1], 0) => TwoPair,
             ([2, 1, 1, 1], 0) | ([1, 1, 1, 1, 1], 1) => OnePair,
             ([1, 1, 1, 1, 1], 0) => HighCard,
-            o => panic!("Invalid hand! {:?}", o),
+            o => return Err(AocError::parse(format!("invalid hand {:?}", o))),
         };
 
-        Hand { kind, cards, bid }
+        Ok(Hand { kind, cards, bid })
     }
 }
 
@@ -109,34 +130,31 @@ impl Ord for Hand {
     }
 }
 
-fn main() {
-    let hands: Vec<String> = read_lines("./inputs/day07").collect();
-
-    println!(
-        "Part 1: {}",
-        run_timed(|| {
-            let mut hs: Vec<Hand> = hands.iter().cloned().map(Hand::from).collect();
-            hs.sort();
-
-            hs.into_iter()
-                .enumerate()
-                .map(|(idx, h)| h.bid * (idx + 1) as u64)
-                .sum::<u64>()
-        })
-    );
-    println!(
-        "Part 2: {}",
-        run_timed(|| {
-            let mut hs: Vec<Hand> = hands
-                .iter()
-                .cloned()
-                .map(|s| Hand::from(s.replace('J', "Z")))
-                .collect();
-            hs.sort();
-            hs.into_iter()
-                .enumerate()
-                .map(|(idx, h)| h.bid * (idx + 1) as u64)
-                .sum::<u64>()
-        })
-    );
+fn total_winnings(mut hands: Vec<Hand>) -> u64 {
+    hands.sort();
+    hands
+        .into_iter()
+        .enumerate()
+        .map(|(idx, h)| h.bid * (idx + 1) as u64)
+        .sum::<u64>()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    // Part 2 reads every J as a joker.
+    type Input = (Vec<Hand>, Vec<Hand>);
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        let hands = parse_lines(lines, |l| Ok((l.parse()?, l.replace('J', "Z").parse()?)))?;
+        Ok(hands.into_iter().unzip())
+    }
+
+    fn part1((hands, _): &Self::Input) -> impl Display {
+        total_winnings(hands.clone())
+    }
+
+    fn part2((_, hands): &Self::Input) -> impl Display {
+        total_winnings(hands.clone())
+    }
 }
@@ -0,0 +1,112 @@
+use crate::{AocError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+type Adjacency = HashMap<String, (String, String)>;
+
+fn read_input(mut lines: impl Iterator<Item = String>) -> Result<(String, Adjacency), AocError> {
+    let route = lines
+        .next()
+        .ok_or_else(|| AocError::parse("input is empty"))?;
+    if route.is_empty() || !route.chars().all(|c| c == 'L' || c == 'R') {
+        return Err(AocError::parse(format!("invalid route {:?}", route)).on_line(0));
+    }
+    lines.next();
+
+    let mut adjacency = HashMap::new();
+
+    for (idx, l) in (2..).zip(lines) {
+        let (from, targets) = l
+            .split_once(" = ")
+            .ok_or_else(|| AocError::parse("missing ' = ' after node").on_line(idx))?;
+        let (l_target, r_target) = targets
+            .strip_prefix("(")
+            .and_then(|t| t.strip_suffix(")"))
+            .and_then(|t| t.split_once(", "))
+            .ok_or_else(|| {
+                AocError::parse(format!("invalid targets {:?}", targets)).on_line(idx)
+            })?;
+
+        adjacency.insert(
+            String::from(from),
+            (String::from(l_target), String::from(r_target)),
+        );
+    }
+
+    // Routes are followed blindly, so every target must lead somewhere.
+    for (l_target, r_target) in adjacency.values() {
+        for target in [l_target, r_target] {
+            if !adjacency.contains_key(target) {
+                return Err(AocError::invalid(format!(
+                    "node {} is never defined",
+                    target
+                )));
+            }
+        }
+    }
+
+    // Part 1 walks from AAA until it reaches ZZZ, which never ends without both.
+    for node in ["AAA", "ZZZ"] {
+        if !adjacency.contains_key(node) {
+            return Err(AocError::invalid(format!("part 1 needs a node {}", node)));
+        }
+    }
+    Ok((route, adjacency))
+}
+
+fn process_route(
+    start: &String,
+    route: &str,
+    adjacency: &Adjacency,
+    target: fn(&String) -> bool,
+) -> u64 {
+    let mut route_eternal = route.chars().cycle();
+
+    let mut step_count = 0;
+    let mut position = start;
+
+    while !target(position) {
+        if let Some(direction) = route_eternal.next() {
+            if let Some((l_target, r_target)) = adjacency.get(position) {
+                if direction == 'L' {
+                    position = l_target;
+                } else {
+                    position = r_target;
+                }
+                step_count += 1
+            }
+        }
+    }
+
+    step_count
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (String, Adjacency);
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        read_input(lines)
+    }
+
+    fn part1((route, adjacency): &Self::Input) -> impl Display {
+        process_route(&String::from("AAA"), route, adjacency, |s| s == "ZZZ")
+    }
+
+    fn part2((route, adjacency): &Self::Input) -> impl Display {
+        let route_starts: Vec<&String> = adjacency.keys().filter(|s| s.ends_with('A')).collect();
+
+        let route_lengths: Vec<u128> = route_starts
+            .into_iter()
+            .map(|start| process_route(start, route, adjacency, |s| s.ends_with('Z')) as u128)
+            .collect();
+
+        let max = *route_lengths.iter().max().unwrap();
+        let mut current_max = max;
+        while !route_lengths.iter().all(|n| current_max.is_multiple_of(*n)) {
+            current_max += max;
+        }
+        current_max
+    }
+}
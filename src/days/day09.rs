@@ -0,0 +1,63 @@
+use crate::{parse_lines, parse_num, AocError, Solution};
+use std::fmt::Display;
+
+fn derive(input: &[i64]) -> Vec<i64> {
+    input
+        .windows(2)
+        .filter_map(|i| match i {
+            [l, r] => Some(*r - *l),
+            _ => None,
+        })
+        .collect()
+}
+
+fn extrapolate(input: &[i64]) -> i64 {
+    if input.iter().all(|e| *e == 0) {
+        0
+    } else {
+        let next = derive(input);
+        let next_diff = extrapolate(&next);
+
+        if let Some(e) = input.last() {
+            *e + next_diff
+        } else {
+            panic!("Extrapolate called with empty collection!");
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        parse_lines(lines, |s| {
+            let sequence: Vec<i64> = s
+                .split_whitespace()
+                .map(parse_num)
+                .collect::<Result<_, _>>()?;
+            if sequence.is_empty() {
+                return Err(AocError::parse("empty sequence"));
+            }
+            Ok(sequence)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input.iter().map(|s| extrapolate(s)).sum::<i64>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let reversed: Vec<Vec<i64>> = input
+            .iter()
+            .map(|s| {
+                let mut new_s = s.clone();
+                new_s.reverse();
+                new_s
+            })
+            .collect();
+
+        reversed.iter().map(|s| extrapolate(s)).sum::<i64>()
+    }
+}
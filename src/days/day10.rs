@@ -1,34 +1,14 @@
-use crate::Direction::{East, North, South, West};
-use crate::MapTile::{
+use self::MapTile::{
     Ground, Horizontal, NorthToEast, NorthToWest, SouthToEast, SouthToWest, Start, Vertical,
 };
-use aoc2023::{read_lines, run_timed};
+use crate::geometry::Direction::{self, East, North, South, West};
+use crate::geometry::UPoint;
+use crate::{AocError, Grid, Solution};
 use std::collections::HashSet;
-
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    fn all() -> [Direction; 4] {
-        [North, South, East, West]
-    }
-    fn opposite(&self) -> Direction {
-        match self {
-            North => South,
-            South => North,
-            East => West,
-            West => East,
-        }
-    }
-}
+use std::fmt::Display;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
-enum MapTile {
+pub enum MapTile {
     Vertical,
     Horizontal,
     NorthToEast,
@@ -53,11 +33,11 @@ impl MapTile {
     fn get_new_dir(&self, old_dir: &Direction) -> Direction {
         match self {
             Vertical => match old_dir {
-                North | South => old_dir.clone(),
+                North | South => *old_dir,
                 _ => panic!("Invalid direction {:?} for Vertical", old_dir),
             },
             Horizontal => match old_dir {
-                West | East => old_dir.clone(),
+                West | East => *old_dir,
                 _ => panic!("Invalid direction {:?} for Horizontal", old_dir),
             },
             NorthToEast => match old_dir {
@@ -85,77 +65,39 @@ impl MapTile {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-struct Position {
-    x: i32,
-    y: i32,
-}
-
-impl Position {
-    fn step(&self, dir: &Direction) -> Position {
-        match dir {
-            North => Position {
-                x: self.x,
-                y: self.y - 1,
-            },
-            South => Position {
-                x: self.x,
-                y: self.y + 1,
-            },
-            East => Position {
-                x: self.x + 1,
-                y: self.y,
-            },
-            West => Position {
-                x: self.x - 1,
-                y: self.y,
-            },
-        }
-    }
-}
-
 #[derive(Debug)]
-struct Map(Vec<Vec<MapTile>>);
+pub struct Map(Grid<MapTile>);
 
 impl Map {
-    fn from_file() -> Map {
-        let parsed = read_lines("./inputs/day10")
-            .map(|l| {
-                l.chars()
-                    .map(|c| match c {
-                        '|' => Vertical,
-                        '-' => Horizontal,
-                        'L' => NorthToEast,
-                        'J' => NorthToWest,
-                        '7' => SouthToWest,
-                        'F' => SouthToEast,
-                        'S' => Start,
-                        '.' => Ground,
-                        _ => panic!("Invalid character: {}", c),
-                    })
-                    .collect()
-            })
-            .collect();
-
-        Map(parsed)
-    }
-
-    fn get(&self, position: &Position) -> Option<&MapTile> {
-        if position.x < 0 || position.y < 0 {
-            None
-        } else {
-            self.0
-                .get(position.y as usize)
-                .and_then(|xs| xs.get(position.x as usize))
+    fn from_lines(lines: impl Iterator<Item = String>) -> Result<Map, AocError> {
+        let parsed = Grid::parse(lines, |c| match c {
+            '|' => Ok(Vertical),
+            '-' => Ok(Horizontal),
+            'L' => Ok(NorthToEast),
+            'J' => Ok(NorthToWest),
+            '7' => Ok(SouthToWest),
+            'F' => Ok(SouthToEast),
+            'S' => Ok(Start),
+            '.' => Ok(Ground),
+            _ => Err(AocError::parse(format!("invalid character {:?}", c))),
+        })?;
+        let map = Map(parsed);
+
+        match map.find_start() {
+            None => Err(AocError::invalid("map has no start tile")),
+            Some(start) if map.get_valid_directions(start).is_empty() => {
+                Err(AocError::invalid("no pipe connects to the start tile"))
+            }
+            Some(_) => Ok(map),
         }
     }
 
-    fn get_valid_directions(&self, pos: &Position) -> Vec<Direction> {
+    fn get_valid_directions(&self, pos: UPoint) -> Vec<Direction> {
         let mut result = Vec::new();
 
-        for dir in Direction::all() {
-            if let Some(tile) = self.get(&pos.step(&dir)) {
-                if tile.connects(&dir.opposite()) {
+        for dir in Direction::ALL {
+            if let Some(next) = self.0.step(pos, dir) {
+                if self.0[next].connects(&dir.opposite()) {
                     result.push(dir)
                 }
             }
@@ -164,39 +106,22 @@ impl Map {
         result
     }
 
-    fn find_start(&self) -> Option<Position> {
-        for (y, xs) in self.0.iter().enumerate() {
-            for (x, tile) in xs.iter().enumerate() {
-                if *tile == Start {
-                    return Some(Position {
-                        x: x as i32,
-                        y: y as i32,
-                    });
-                }
-            }
-        }
-        return None;
-    }
-
-    fn rows_iter(&self) -> impl Iterator<Item = (usize, &Vec<MapTile>)> {
-        self.0.iter().enumerate()
+    fn find_start(&self) -> Option<UPoint> {
+        self.0.find(|tile| *tile == Start)
     }
 }
 
-fn compute_loop(map: &Map) -> Vec<Position> {
+fn compute_loop(map: &Map) -> Vec<UPoint> {
     let start = map.find_start().unwrap();
     let mut loop_positions = Vec::new();
-    let mut current_direction = map.get_valid_directions(&start).first().unwrap().clone();
-    let mut current_pos = start.step(&current_direction);
-    loop_positions.push(current_pos.clone());
+    let mut current_direction = *map.get_valid_directions(start).first().unwrap();
+    let mut current_pos = map.0.step(start, current_direction).unwrap();
+    loop_positions.push(current_pos);
 
     while current_pos != start {
-        current_direction = map
-            .get(&current_pos)
-            .unwrap()
-            .get_new_dir(&current_direction);
-        current_pos = current_pos.step(&current_direction);
-        loop_positions.push(current_pos.clone())
+        current_direction = map.0[current_pos].get_new_dir(&current_direction);
+        current_pos = map.0.step(current_pos, current_direction).unwrap();
+        loop_positions.push(current_pos)
     }
 
     loop_positions
@@ -208,24 +133,21 @@ fn part1(map: &Map) -> i32 {
 }
 
 fn part2(map: &Map) -> i32 {
-    let full_loop_points: HashSet<Position> = HashSet::from_iter(compute_loop(map));
+    let full_loop_points: HashSet<UPoint> = HashSet::from_iter(compute_loop(map));
     let mut contained_count = 0;
 
-    for (y, row) in map.rows_iter() {
+    for (y, row) in map.0.rows().enumerate() {
         let mut loop_intersections = 0;
         let mut loop_enter: Option<MapTile> = None;
         for (x, tile) in row.iter().enumerate() {
-            let pos = Position {
-                x: x as i32,
-                y: y as i32,
-            };
+            let pos = UPoint::new(x, y);
             if full_loop_points.contains(&pos) {
                 match (tile, loop_enter) {
                     // Hack (start == vertical) that may only work for my input.
                     (Vertical | Start, None) => loop_intersections += 1,
                     // Encountering one if these means we'll be going parallel to a pipe in the loop for a bit.
                     // This may, or may not, lead to a crossing depending on the bend at the end of the pipeline.
-                    (NorthToEast | SouthToEast, None) => loop_enter = Some(tile.clone()),
+                    (NorthToEast | SouthToEast, None) => loop_enter = Some(*tile),
                     // This is the "enter from north go along horizontal, then exit further south" case
                     // I.e.: a crossing.
                     (NorthToWest, Some(SouthToEast)) | (SouthToWest, Some(NorthToEast)) => {
@@ -251,9 +173,20 @@ fn part2(map: &Map) -> i32 {
     contained_count
 }
 
-fn main() {
-    let map = Map::from_file();
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        Map::from_lines(lines)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
 
-    println!("Part 1: {}", run_timed(|| part1(&map)));
-    println!("Part 2: {}", run_timed(|| part2(&map)));
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
 }
@@ -0,0 +1,139 @@
+use crate::geometry::UPoint;
+use crate::{AocError, Part, Solution, Variant};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct StarMap {
+    stars: Vec<UPoint>,
+    y_size: usize,
+    x_size: usize,
+}
+
+impl StarMap {
+    fn from_lines(lines: impl Iterator<Item = String>) -> Result<StarMap, AocError> {
+        let mut stars: Vec<UPoint> = Vec::new();
+        for (y, l) in lines.enumerate() {
+            for (x, c) in l.chars().enumerate() {
+                match c {
+                    '#' => stars.push(UPoint::new(x, y)),
+                    '.' => {}
+                    _ => {
+                        return Err(AocError::parse(format!("invalid character {:?}", c)).on_line(y))
+                    }
+                }
+            }
+        }
+        if stars.is_empty() {
+            return Err(AocError::invalid("no galaxies in the image"));
+        }
+
+        Ok(StarMap::from_points(stars))
+    }
+
+    fn from_points(stars: Vec<UPoint>) -> StarMap {
+        let y_size = stars.iter().map(|p| p.y).max().unwrap() + 1;
+        let x_size = stars.iter().map(|p| p.x).max().unwrap() + 1;
+
+        StarMap {
+            stars,
+            y_size,
+            x_size,
+        }
+    }
+
+    fn expand(&self, factor: usize) -> StarMap {
+        let mut xs: HashSet<usize> = (0..self.x_size).collect();
+        let mut ys: HashSet<usize> = (0..self.y_size).collect();
+
+        for UPoint { x, y } in self.stars.iter() {
+            xs.remove(x);
+            ys.remove(y);
+        }
+
+        let expanded_stars = self
+            .stars
+            .iter()
+            .map(|UPoint { x, y }| {
+                let x_expand = xs.iter().filter(|i| **i < *x).count();
+                let y_expand = ys.iter().filter(|i| **i < *y).count();
+
+                UPoint {
+                    x: x + (x_expand * (factor - 1)),
+                    y: y + (y_expand * (factor - 1)),
+                }
+            })
+            .collect();
+
+        Self::from_points(expanded_stars)
+    }
+}
+
+fn distance_sum(stars: StarMap) -> u64 {
+    let mut star_map = stars.stars;
+    let mut distance_sum = 0;
+
+    while let Some(elem) = star_map.pop() {
+        for other in star_map.iter() {
+            distance_sum += elem.manhattan(*other) as u64
+        }
+    }
+
+    distance_sum
+}
+
+// Sums the distances one axis at a time over sorted coordinates, instead of over every pair.
+// Empty rows and columns are expanded on the way, so there is no expanded star map either.
+fn distance_sum_sorted(stars: &StarMap, factor: usize) -> u64 {
+    let xs = stars.stars.iter().map(|p| p.x).collect();
+    let ys = stars.stars.iter().map(|p| p.y).collect();
+    axis_distance_sum(xs, factor) + axis_distance_sum(ys, factor)
+}
+
+fn axis_distance_sum(mut coords: Vec<usize>, factor: usize) -> u64 {
+    coords.sort_unstable();
+
+    let mut sum = 0;
+    // Sum of the expanded coordinates before the current one.
+    let mut before = 0;
+    let mut expanded = 0;
+    for (idx, pair) in coords.windows(2).enumerate() {
+        // Every line strictly between two occupied lines is empty.
+        let empty = (pair[1] - pair[0]).saturating_sub(1);
+        before += expanded;
+        expanded += (pair[1] - pair[0] + empty * (factor - 1)) as u64;
+        sum += expanded * (idx as u64 + 1) - before;
+    }
+    sum
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = StarMap;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        StarMap::from_lines(lines)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        distance_sum(input.expand(2))
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        distance_sum(input.expand(1_000_000))
+    }
+
+    const VARIANTS: &'static [Variant<Self::Input>] = &[
+        Variant {
+            name: "sorted sums",
+            part: Part::One,
+            solve: |stars| distance_sum_sorted(stars, 2).to_string(),
+        },
+        Variant {
+            name: "sorted sums",
+            part: Part::Two,
+            solve: |stars| distance_sum_sorted(stars, 1_000_000).to_string(),
+        },
+    ];
+}
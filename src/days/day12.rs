@@ -1,6 +1,8 @@
-use crate::SpringState::{Damaged, Operational, Unknown};
-use aoc2023::{read_lines, run_timed};
+use self::SpringState::{Damaged, Operational, Unknown};
+use crate::{parse_lines, parse_num, AocError, Solution};
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 enum SpringState {
@@ -9,40 +11,51 @@ enum SpringState {
     Operational,
 }
 
-impl SpringState {
-    fn from(c: char) -> SpringState {
+impl TryFrom<char> for SpringState {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<SpringState, AocError> {
         match c {
-            '#' => Damaged,
-            '.' => Operational,
-            '?' => Unknown,
-            _ => panic!("Invalid spring state: {:?}", c),
+            '#' => Ok(Damaged),
+            '.' => Ok(Operational),
+            '?' => Ok(Unknown),
+            _ => Err(AocError::parse(format!("invalid spring state {:?}", c))),
         }
     }
 }
 
 #[derive(Debug, Clone, Hash)]
-struct SpringData {
+pub struct SpringData {
     history: Vec<SpringState>,
     damage_groups: Vec<usize>,
 }
 
-impl SpringData {
-    fn from(l: String) -> SpringData {
+impl FromStr for SpringData {
+    type Err = AocError;
+
+    fn from_str(l: &str) -> Result<SpringData, AocError> {
         if let Some((hist_section, dmg_groups_section)) = l.split_once(" ") {
-            let history = hist_section.chars().map(SpringState::from).collect();
+            let history = hist_section
+                .chars()
+                .map(SpringState::try_from)
+                .collect::<Result<_, _>>()?;
             let damage_groups = dmg_groups_section
                 .split(',')
-                .map(|n| n.parse().unwrap())
-                .collect();
-            SpringData {
+                .map(parse_num)
+                .collect::<Result<_, _>>()?;
+            Ok(SpringData {
                 history,
                 damage_groups,
-            }
+            })
         } else {
-            panic!("Invalid line {:?}", l)
+            Err(AocError::parse(
+                "missing ' ' between springs and damage groups",
+            ))
         }
     }
+}
 
+impl SpringData {
     fn expand(&self) -> SpringData {
         let mut expanded_history = self.history.clone();
         for _ in 0..4 {
@@ -84,10 +97,8 @@ type Memory = HashMap<(usize, usize), Res>;
 
 fn put_group(group: usize, line: &[SpringState], groups: &[usize], memory: &mut Memory) -> Res {
     //Check if group fits in the line.
-    if group > line.len() {
-        Res::Invalid
-    // Check we do not conflict with known data in the line
-    } else if line[..group].iter().any(|e| *e == Operational) {
+    // And check we do not conflict with known data in the line
+    if group > line.len() || line[..group].contains(&Operational) {
         Res::Invalid
     //Check if we fit exactly (end of line group)
     } else if group == line.len() {
@@ -133,7 +144,7 @@ fn count_options(line: &[SpringState], groups: &[usize], memory: &mut Memory) ->
             }
             [] => {
                 //We're through the line but a group is left -> invalid
-                if groups.len() > 0 {
+                if !groups.is_empty() {
                     Res::Invalid
                 } else {
                     Res::Valid(1)
@@ -145,35 +156,36 @@ fn count_options(line: &[SpringState], groups: &[usize], memory: &mut Memory) ->
     }
 }
 
-fn main() {
-    let input: Vec<SpringData> = read_lines("./inputs/day12").map(SpringData::from).collect();
-
-    println!(
-        "Part 1: {}",
-        run_timed(|| {
-            input
-                .iter()
-                .map(|l| {
-                    count_options(&l.history[..], &l.damage_groups[..], &mut Memory::new()).value()
-                })
-                .sum::<u64>()
-        })
-    );
-    println!(
-        "Part 1: {}",
-        run_timed(|| {
-            input
-                .iter()
-                .map(|l| {
-                    let expanded = l.expand();
-                    count_options(
-                        &expanded.history[..],
-                        &expanded.damage_groups[..],
-                        &mut Memory::new(),
-                    )
-                    .value()
-                })
-                .sum::<u64>()
-        })
-    );
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<SpringData>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        parse_lines(lines, str::parse)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|l| {
+                count_options(&l.history[..], &l.damage_groups[..], &mut Memory::new()).value()
+            })
+            .sum::<u64>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|l| {
+                let expanded = l.expand();
+                count_options(
+                    &expanded.history[..],
+                    &expanded.damage_groups[..],
+                    &mut Memory::new(),
+                )
+                .value()
+            })
+            .sum::<u64>()
+    }
 }
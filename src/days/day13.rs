@@ -0,0 +1,135 @@
+use crate::{AocError, Grid, Solution};
+use std::fmt::Display;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum FieldElem {
+    Rock,
+    Ash,
+}
+
+impl TryFrom<char> for FieldElem {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<FieldElem, AocError> {
+        match c {
+            '.' => Ok(FieldElem::Ash),
+            '#' => Ok(FieldElem::Rock),
+            _ => Err(AocError::parse(format!("invalid element {:?}", c))),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum ReflectionLine {
+    Col(usize),
+    Row(usize),
+}
+
+impl ReflectionLine {
+    fn get_score(&self) -> usize {
+        match self {
+            ReflectionLine::Col(n) => *n,
+            ReflectionLine::Row(n) => 100 * n,
+        }
+    }
+}
+
+// A field as rows, and transposed so its columns can be compared as rows too.
+#[derive(Debug, Clone)]
+pub struct Field {
+    rows: Grid<FieldElem>,
+    cols: Grid<FieldElem>,
+}
+
+impl Field {
+    fn from_lines(lines: &[String]) -> Result<Field, AocError> {
+        let rows = Grid::parse(lines, FieldElem::try_from)?;
+        let cols = rows.transpose();
+        Ok(Field { rows, cols })
+    }
+
+    fn get_reflection_candidates(&self, required_diff: usize) -> Vec<ReflectionLine> {
+        let check = |grid: &Grid<FieldElem>, idx: usize| -> bool {
+            count_mismatched_elems(grid.row(idx), grid.row(idx + 1)) <= required_diff
+        };
+        let rows = (0..self.rows.height() - 1)
+            .filter(|&idx| check(&self.rows, idx))
+            .map(|idx| ReflectionLine::Row(idx + 1));
+        let cols = (0..self.cols.height() - 1)
+            .filter(|&idx| check(&self.cols, idx))
+            .map(|idx| ReflectionLine::Col(idx + 1));
+
+        rows.chain(cols).collect()
+    }
+
+    fn find_reflection_line(&self, required_diff: usize) -> ReflectionLine {
+        let candidates = self.get_reflection_candidates(required_diff);
+
+        let mut result = candidates.into_iter().filter(|candidate| {
+            let (idx, grid) = match candidate {
+                ReflectionLine::Col(i) => (i, &self.cols),
+                ReflectionLine::Row(i) => (i, &self.rows),
+            };
+            let to_right = (0usize..*idx).rev();
+            let to_left = *idx..grid.height();
+
+            let diff: usize = to_right
+                .zip(to_left)
+                .map(|(l, r)| count_mismatched_elems(grid.row(l), grid.row(r)))
+                .sum();
+
+            diff == required_diff
+        });
+
+        if let Some(rl) = result.next() {
+            rl
+        } else {
+            panic!("No reflection in field?!")
+        }
+    }
+}
+
+fn count_mismatched_elems<T: Eq>(l: &[T], r: &[T]) -> usize {
+    if l.len() != r.len() {
+        panic!("Cannot count mismatched in unequal slices")
+    }
+    l.iter().zip(r.iter()).filter(|(le, re)| le != re).count()
+}
+
+fn read_input(lines: impl Iterator<Item = String>) -> Result<Vec<Field>, AocError> {
+    let lines: Vec<String> = lines.collect();
+    let mut fields = Vec::new();
+    let mut first_line = 0;
+
+    for block in lines.split(|l| l.is_empty()) {
+        if !block.is_empty() {
+            fields.push(Field::from_lines(block).map_err(|e| e.shift_lines(first_line))?);
+        }
+        first_line += block.len() + 1;
+    }
+    Ok(fields)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Field>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        read_input(lines)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|f| f.find_reflection_line(0).get_score())
+            .sum::<usize>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|f| f.find_reflection_line(1).get_score())
+            .sum::<usize>()
+    }
+}
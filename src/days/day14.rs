@@ -0,0 +1,158 @@
+use self::Elem::{Cube, Empty, Round};
+use crate::progress::{Cancellable, Ticker};
+use crate::span;
+use crate::{AocError, Grid, Part, Solution, Variant};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+pub enum Elem {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl TryFrom<char> for Elem {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Elem, AocError> {
+        match c {
+            '#' => Ok(Cube),
+            'O' => Ok(Round),
+            '.' => Ok(Empty),
+            _ => Err(AocError::parse(format!("invalid field element {:?}", c))),
+        }
+    }
+}
+
+pub type Field = Grid<Elem>;
+
+fn shift_north(fld: &mut Field) {
+    for x in 0..fld.width() {
+        // Where the next round rock in this column comes to rest.
+        let mut free = 0;
+        for y in 0..fld.height() {
+            match fld[(x, y)] {
+                Cube => free = y + 1,
+                Round => {
+                    fld.swap((x, free), (x, y));
+                    free += 1;
+                }
+                Empty => {}
+            }
+        }
+    }
+}
+
+// Tilting north and turning clockwise four times tilts north, west, south and east in turn.
+fn spin_cycle(fld: &Field) -> Field {
+    let mut fld = fld.clone();
+    for _ in 0..4 {
+        shift_north(&mut fld);
+        fld = fld.rotate_clockwise();
+    }
+    fld
+}
+
+fn field_score(field: &Field) -> u64 {
+    let len = field.height();
+    field
+        .rows()
+        .enumerate()
+        .map(|(y, row)| {
+            row.iter()
+                .map(|e| if *e == Round { (len - y) as u64 } else { 0 })
+                .sum::<u64>()
+        })
+        .sum()
+}
+
+fn part1(field: &Field) -> u64 {
+    let mut work_field = field.clone();
+
+    shift_north(&mut work_field);
+
+    field_score(&work_field)
+}
+
+fn part2(field: &Field) -> Cancellable<u64> {
+    let mut work_field = field.clone();
+    let mut counter = 0;
+    let mut past_fields: Vec<Field> = vec![work_field.clone()];
+    let mut first_match_idx = None;
+    let ticker = Ticker::start("cycles", Some(1_000_000_000));
+
+    let find_span = span::enter("find cycle");
+    while counter < 1_000_000_000 {
+        if !ticker.tick() {
+            return Cancellable::Cancelled;
+        }
+        work_field = span::time("spin", || spin_cycle(&work_field));
+
+        let seen = span::time("compare", || {
+            past_fields.iter().position(|f| f == &work_field)
+        });
+        if let Some(idx) = seen {
+            first_match_idx = Some(idx);
+            break;
+        }
+        past_fields.push(work_field.clone());
+
+        counter += 1;
+    }
+    drop(find_span);
+
+    // Without a repeat within the billion cycles, the last field is the one asked for.
+    let Some(first_match_idx) = first_match_idx else {
+        return Cancellable::Done(field_score(&work_field));
+    };
+    let loop_len = past_fields.len() - first_match_idx;
+    let rem = (1_000_000_000 - first_match_idx) % loop_len;
+
+    Cancellable::Done(field_score(past_fields.get(first_match_idx + rem).unwrap()))
+}
+
+// Looks every field up in a map instead of scanning all the fields seen so far.
+fn part2_hashing(field: &Field) -> u64 {
+    const CYCLES: usize = 1_000_000_000;
+    let mut seen: HashMap<Field, usize> = HashMap::new();
+    // Only the scores of the fields seen are needed once the cycle is found.
+    let mut scores = Vec::new();
+    let mut work_field = field.clone();
+
+    for cycle in 0..CYCLES {
+        if let Some(&first) = seen.get(&work_field) {
+            let rem = (CYCLES - first) % (cycle - first);
+            return scores[first + rem];
+        }
+        scores.push(field_score(&work_field));
+        let next = spin_cycle(&work_field);
+        seen.insert(work_field, cycle);
+        work_field = next;
+    }
+    field_score(&work_field)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Field;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        Grid::parse(lines, Elem::try_from)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+
+    const VARIANTS: &'static [Variant<Self::Input>] = &[Variant {
+        name: "hashing",
+        part: Part::Two,
+        solve: |field| part2_hashing(field).to_string(),
+    }];
+}
@@ -1,12 +1,14 @@
-use crate::Instr::{Place, Remove};
-use aoc2023::{read_lines, run_timed};
+use self::Instr::{Place, Remove};
+use crate::{parse_num, AocError, Solution};
+use std::fmt::Display;
+use std::str::FromStr;
 
 fn aoc_hash(s: &str) -> u16 {
     s.bytes().fold(0, |acc, b| (acc + b as u16) * 17 % 256)
 }
 
-#[derive(Eq, PartialEq, Debug)]
-struct Lens {
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Lens {
     label: String,
     hash: u16,
 }
@@ -20,19 +22,23 @@ impl Lens {
     }
 }
 
-#[derive(Debug)]
-enum Instr {
+#[derive(Debug, Clone)]
+pub enum Instr {
     Remove(Lens),
     Place(Lens, u32),
 }
 
-impl Instr {
-    fn from(s: &str) -> Instr {
-        if s.ends_with('-') {
-            Remove(Lens::from(&s[..s.len() - 1]))
+impl FromStr for Instr {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Instr, AocError> {
+        if let Some(label) = s.strip_suffix('-') {
+            Ok(Remove(Lens::from(label)))
         } else {
-            let (label, f) = s.split_once('=').unwrap();
-            Place(Lens::from(label), f.parse().unwrap())
+            let (label, f) = s
+                .split_once('=')
+                .ok_or_else(|| AocError::parse(format!("invalid step {:?}", s)))?;
+            Ok(Place(Lens::from(label), parse_num(f)?))
         }
     }
 }
@@ -88,34 +94,46 @@ impl Boxes {
     }
 }
 
-fn part2(raw_instrs: &Vec<String>) -> u32 {
-    let instrs = raw_instrs.iter().map(|s| Instr::from(s));
+fn part2(instrs: &[Instr]) -> u32 {
     let mut boxes = Boxes::new();
 
-    for i in instrs {
+    for i in instrs.iter().cloned() {
         boxes.run_instr(i);
     }
 
     boxes.score()
 }
 
-fn main() {
-    let raw_instrs: Vec<String> = read_lines("./inputs/day15")
-        .next()
-        .unwrap()
-        .split(',')
-        .map(String::from)
-        .collect();
-
-    println!(
-        "Part 1: {}",
-        run_timed(|| {
-            raw_instrs
-                .iter()
-                .map(|s| aoc_hash(s))
-                .fold(0u32, |acc, h| acc + h as u32)
-        })
-    );
-
-    println!("Part 2: {}", run_timed(|| { part2(&raw_instrs) }));
+pub struct Day15;
+
+impl Solution for Day15 {
+    // Part 1 hashes the raw steps, part 2 runs them as instructions.
+    type Input = (Vec<String>, Vec<Instr>);
+
+    fn parse(mut lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        let raw_instrs: Vec<String> = lines
+            .next()
+            .ok_or_else(|| AocError::parse("input is empty"))?
+            .split(',')
+            .map(String::from)
+            .collect();
+        let instrs = raw_instrs
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|e: AocError| e.on_line(0))?;
+
+        Ok((raw_instrs, instrs))
+    }
+
+    fn part1((raw_instrs, _): &Self::Input) -> impl Display {
+        raw_instrs
+            .iter()
+            .map(|s| aoc_hash(s))
+            .fold(0u32, |acc, h| acc + h as u32)
+    }
+
+    fn part2((_, instrs): &Self::Input) -> impl Display {
+        part2(instrs)
+    }
 }
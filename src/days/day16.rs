@@ -0,0 +1,134 @@
+use crate::geometry::Direction::{self, East, North, South, West};
+use crate::geometry::UPoint;
+use crate::{AocError, Grid, Solution};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+use std::iter::successors;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Element {
+    RightToDownMirror,
+    RightToUpMirror,
+    Empty,
+    HorizontalSplit,
+    VerticalSplit,
+}
+
+impl TryFrom<char> for Element {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Element, AocError> {
+        match c {
+            '.' => Ok(Element::Empty),
+            '\\' => Ok(Element::RightToDownMirror),
+            '/' => Ok(Element::RightToUpMirror),
+            '-' => Ok(Element::HorizontalSplit),
+            '|' => Ok(Element::VerticalSplit),
+            _ => Err(AocError::parse(format!("bad map element {:?}", c))),
+        }
+    }
+}
+
+impl Element {
+    fn new_dirs(&self, current: Direction) -> Vec<Direction> {
+        match self {
+            Element::RightToDownMirror => match current {
+                North => vec![West],
+                South => vec![East],
+                West => vec![North],
+                East => vec![South],
+            },
+            Element::RightToUpMirror => match current {
+                North => vec![East],
+                South => vec![West],
+                West => vec![South],
+                East => vec![North],
+            },
+            Element::HorizontalSplit => match current {
+                North | South => vec![West, East],
+                _ => vec![current],
+            },
+            Element::VerticalSplit => match current {
+                West | East => vec![North, South],
+                _ => vec![current],
+            },
+            Element::Empty => vec![current],
+        }
+    }
+}
+
+pub type Map = Grid<Element>;
+
+// The points a beam passes, from `start` (exclusive) to the edge of the map.
+fn beam(map: &Map, start: UPoint, dir: Direction) -> impl Iterator<Item = UPoint> + '_ {
+    successors(map.step(start, dir), move |p| map.step(*p, dir))
+}
+
+fn count_energized(map: &Map, start: UPoint, start_dir: Direction) -> usize {
+    let mut visited: HashSet<(Direction, UPoint)> = HashSet::new();
+    let mut to_visit: VecDeque<(Direction, UPoint)> = VecDeque::new();
+
+    //Start state: just visited the start node from the start direction.
+    visited.insert((start_dir, start));
+    for dir in map[start].new_dirs(start_dir) {
+        to_visit.push_back((dir, start));
+    }
+
+    while let Some((dir, path_start)) = to_visit.pop_front() {
+        for p in beam(map, path_start, dir) {
+            // if we're at an element in the same direction, it is a loop.
+            if !visited.insert((dir, p)) {
+                break;
+            }
+            match map[p] {
+                Element::Empty => {} //carry on.
+                other => {
+                    for new_dir in other.new_dirs(dir) {
+                        to_visit.push_back((new_dir, p))
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    visited
+        .into_iter()
+        .map(|(_, p)| p)
+        .collect::<HashSet<UPoint>>()
+        .len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        Grid::parse(lines, Element::try_from)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        count_energized(input, UPoint::new(0, 0), East)
+    }
+
+    fn part2(map: &Self::Input) -> impl Display {
+        let mut starting_points: Vec<(UPoint, Direction)> = Vec::new();
+        for y in 0..map.height() {
+            let big_x = map.width() - 1;
+            starting_points.push((UPoint::new(0, y), East));
+            starting_points.push((UPoint::new(big_x, y), West));
+        }
+        for x in 0..map.width() {
+            let big_y = map.height() - 1;
+            starting_points.push((UPoint::new(x, 0), South));
+            starting_points.push((UPoint::new(x, big_y), North));
+        }
+
+        starting_points
+            .into_iter()
+            .map(|(p, dir)| count_energized(map, p, dir))
+            .max()
+            .unwrap()
+    }
+}
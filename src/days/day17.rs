@@ -0,0 +1,159 @@
+use crate::geometry::{Direction, UPoint};
+use crate::{AocError, Grid, Solution};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+
+// No turning back: every direction except the one we came from, as long as it stays on the map.
+fn valid_neighbours(
+    costs: &Costs,
+    point: UPoint,
+    incoming: Direction,
+) -> impl Iterator<Item = (Direction, UPoint)> + '_ {
+    Direction::ALL
+        .into_iter()
+        .filter(move |dir| *dir != incoming.opposite())
+        .filter_map(move |dir| costs.step(point, dir).map(|next| (dir, next)))
+}
+
+fn dist(a: UPoint, b: UPoint) -> u32 {
+    a.manhattan(b) as u32
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+struct State {
+    cost: u32,
+    point: UPoint,
+    dir: Direction,
+    steps: u32,
+    dist: u32,
+}
+
+impl PartialOrd<Self> for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for State {
+    // Need to flip ordering for
+    // if cost + estimation == equal, take the one with the lower estimate.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.dist + other.cost).cmp(&(self.cost + self.dist))
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+struct StateKey {
+    point: UPoint,
+    dir: Direction,
+    steps: u32,
+}
+
+impl From<State> for StateKey {
+    fn from(value: State) -> Self {
+        StateKey {
+            point: value.point,
+            dir: value.dir,
+            steps: value.steps,
+        }
+    }
+}
+
+pub type Costs = Grid<u32>;
+
+fn find_cheapest_route(
+    costs: &Costs,
+    start: UPoint,
+    target: UPoint,
+    min_steps: u32,
+    max_steps: u32,
+) -> u32 {
+    let mut to_visit: BinaryHeap<State> = BinaryHeap::new();
+    let mut known_costs: HashMap<StateKey, u32> = HashMap::new();
+    let start_down = State {
+        cost: 0,
+        steps: 0,
+        point: start,
+        dir: Direction::South,
+        dist: dist(start, target),
+    };
+    let start_right = State {
+        cost: 0,
+        steps: 0,
+        point: start,
+        dir: Direction::East,
+        dist: dist(start, target),
+    };
+    to_visit.push(start_down);
+    to_visit.push(start_right);
+    known_costs.insert(start_right.into(), 0);
+    known_costs.insert(start_down.into(), 0);
+
+    while let Some(st) = to_visit.pop() {
+        if st.point == target {
+            return st.cost;
+        }
+        //Already encountered a cheaper use of this point
+        if known_costs.get(&st.into()).is_some_and(|&c| c < st.cost) {
+            continue;
+        }
+
+        for (dir, next) in valid_neighbours(costs, st.point, st.dir) {
+            let new_st = State {
+                cost: st.cost + costs[next],
+                point: next,
+                dir,
+                steps: if dir == st.dir { st.steps + 1 } else { 1 },
+                dist: dist(next, target),
+            };
+            //Skip condition: too many steps, or we know a cheaper alt.
+            //Or (p2) we make a turn before min steps.
+            if new_st.steps > max_steps
+                || known_costs
+                    .get(&new_st.into())
+                    .is_some_and(|&c| c <= new_st.cost)
+                || (new_st.dir != st.dir && st.steps < min_steps)
+            {
+                continue;
+            }
+            to_visit.push(new_st);
+            known_costs.insert(new_st.into(), new_st.cost);
+        }
+    }
+
+    u32::MAX
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Costs;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        Grid::parse(lines, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::parse(format!("invalid heat loss {:?}", c)))
+        })
+    }
+
+    fn part1(costs: &Self::Input) -> impl Display {
+        find_cheapest_route(
+            costs,
+            UPoint::new(0, 0),
+            UPoint::new(costs.width() - 1, costs.height() - 1),
+            1,
+            3,
+        )
+    }
+
+    fn part2(costs: &Self::Input) -> impl Display {
+        find_cheapest_route(
+            costs,
+            UPoint::new(0, 0),
+            UPoint::new(costs.width() - 1, costs.height() - 1),
+            4,
+            10,
+        )
+    }
+}
@@ -1,98 +1,58 @@
-use aoc2023::{read_lines, run_timed};
+use crate::geometry::Direction::{self, East, North, South, West};
+use crate::geometry::Point;
+use crate::{parse_lines, parse_num, AocError, Solution};
 use std::collections::{HashMap, HashSet};
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Dir {
-    Up,
-    Down,
-    Left,
-    Right,
+use std::fmt::Display;
+
+fn parse_dir(c: &str) -> Result<Direction, AocError> {
+    match c {
+        "U" => Ok(North),
+        "D" => Ok(South),
+        "L" => Ok(West),
+        "R" => Ok(East),
+        _ => Err(AocError::parse(format!("invalid dir {:?}", c))),
+    }
 }
 
-impl Dir {
-    fn from(c: &str) -> Dir {
-        match c {
-            "U" => Dir::Up,
-            "D" => Dir::Down,
-            "L" => Dir::Left,
-            "R" => Dir::Right,
-            _ => panic!("Invalid dir: {}", c),
-        }
-    }
-    fn from_p2(c: &str) -> Dir {
-        match c {
-            "0" => Dir::Right,
-            "1" => Dir::Down,
-            "2" => Dir::Left,
-            "3" => Dir::Up,
-            _ => panic!("Invalid dir: {}", c),
-        }
+fn parse_dir_p2(c: &str) -> Result<Direction, AocError> {
+    match c {
+        "0" => Ok(East),
+        "1" => Ok(South),
+        "2" => Ok(West),
+        "3" => Ok(North),
+        _ => Err(AocError::parse(format!("invalid dir {:?}", c))),
     }
 }
 
 #[derive(Debug)]
-struct Instruction {
+pub struct Instruction {
     steps: u32,
-    dir: Dir,
+    dir: Direction,
 }
 
 impl Instruction {
-    fn from_line(line: &str) -> Instruction {
+    fn from_line(line: &str) -> Result<Instruction, AocError> {
         if let [d, st, _co] = line.split_whitespace().collect::<Vec<&str>>()[..] {
-            let dir = Dir::from(d);
-            let steps = st.parse().unwrap();
-            Instruction { dir, steps }
-        } else {
-            panic!("Invalid line: {}", line);
-        }
-    }
-    fn from_file(file_name: &str) -> Vec<Instruction> {
-        read_lines(file_name).map(|l| Self::from_line(&l)).collect()
-    }
-
-    fn from_line_p2(line: &str) -> Instruction {
-        if let Some(n) = line.split_whitespace().skip(2).next() {
-            let steps = u32::from_str_radix(&n[2..7], 16).unwrap();
-            let dir = Dir::from_p2(&n[7..8]);
-            Instruction { steps, dir }
+            let dir = parse_dir(d)?;
+            let steps = parse_num(st)?;
+            Ok(Instruction { dir, steps })
         } else {
-            panic!("Invalid line: {}", line);
+            Err(AocError::parse("expected direction, steps and color"))
         }
     }
-
-    fn from_file_p2(file_name: &str) -> Vec<Instruction> {
-        read_lines(file_name)
-            .map(|l| Self::from_line_p2(&l))
-            .collect()
-    }
-}
-
-#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
-struct Point {
-    x: i64,
-    y: i64,
-}
-
-impl Point {
-    fn next(&self, dir: Dir) -> Point {
-        match dir {
-            Dir::Up => Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Dir::Down => Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Dir::Left => Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Dir::Right => Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-        }
+    fn from_line_p2(line: &str) -> Result<Instruction, AocError> {
+        let color = line
+            .split_whitespace()
+            .nth(2)
+            .and_then(|c| c.strip_prefix("(#"))
+            .and_then(|c| c.strip_suffix(')'))
+            .filter(|c| c.len() == 6 && c.is_ascii())
+            .ok_or_else(|| AocError::parse("expected a color like (#70c710)"))?;
+
+        let steps = u32::from_str_radix(&color[..5], 16)
+            .map_err(|_| AocError::parse(format!("invalid hex distance {:?}", &color[..5])))?;
+        let dir = parse_dir_p2(&color[5..])?;
+        Ok(Instruction { steps, dir })
     }
 }
 
@@ -131,12 +91,12 @@ impl Field {
 
 fn lagoon_size(instrs: &Vec<Instruction>) -> u64 {
     let mut field = Field::new();
-    let mut trench_position = Point { x: 0, y: 0 };
+    let mut trench_position = Point::new(0, 0);
     field.put(trench_position);
 
     for instr in instrs {
         for _ in 0..instr.steps {
-            trench_position = trench_position.next(instr.dir);
+            trench_position = trench_position.step(instr.dir);
             field.put(trench_position)
         }
     }
@@ -185,18 +145,27 @@ fn lagoon_size(instrs: &Vec<Instruction>) -> u64 {
         }
     }
 
-    count as u64
+    count
 }
 
-fn main() {
-    let file_name = "./inputs/day18";
-
-    println!(
-        "Part 1: {}",
-        run_timed(|| lagoon_size(&Instruction::from_file(file_name)))
-    );
-    println!(
-        "Part 1: {}",
-        run_timed(|| lagoon_size(&Instruction::from_file_p2(file_name)))
-    );
+pub struct Day18;
+
+impl Solution for Day18 {
+    // The dig plan is read twice: once by direction & steps, once from the color codes.
+    type Input = (Vec<Instruction>, Vec<Instruction>);
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        let instrs = parse_lines(lines, |l| {
+            Ok((Instruction::from_line(l)?, Instruction::from_line_p2(l)?))
+        })?;
+        Ok(instrs.into_iter().unzip())
+    }
+
+    fn part1((instrs, _): &Self::Input) -> impl Display {
+        lagoon_size(instrs)
+    }
+
+    fn part2((_, instrs): &Self::Input) -> impl Display {
+        lagoon_size(instrs)
+    }
 }
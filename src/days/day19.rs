@@ -0,0 +1,328 @@
+use crate::span;
+use crate::{parse_num, AocError, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Outcome {
+    Ok,
+    Reject,
+    Goto(String),
+}
+
+impl Outcome {
+    fn from(s: &str) -> Outcome {
+        match s {
+            "A" => Outcome::Ok,
+            "R" => Outcome::Reject,
+            other => Outcome::Goto(String::from(other)),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Expression {
+    Gt(String, u32),
+    Lt(String, u32),
+}
+
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+fn category(var: &str) -> Result<String, AocError> {
+    if CATEGORIES.contains(&var) {
+        Ok(String::from(var))
+    } else {
+        Err(AocError::parse(format!("unknown category {:?}", var)))
+    }
+}
+
+impl FromStr for Expression {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Expression, AocError> {
+        if let Some((var, value)) = s.split_once('<') {
+            Ok(Expression::Lt(category(var)?, parse_num(value)?))
+        } else if let Some((var, value)) = s.split_once('>') {
+            Ok(Expression::Gt(category(var)?, parse_num(value)?))
+        } else {
+            Err(AocError::parse(format!("invalid expression {:?}", s)))
+        }
+    }
+}
+
+impl Expression {
+    fn check(&self, context: &Part) -> bool {
+        match self {
+            Expression::Gt(r, v) => context.get(r).unwrap() > v,
+            Expression::Lt(r, v) => context.get(r).unwrap() < v,
+        }
+    }
+
+    fn var(&self) -> &str {
+        match self {
+            Expression::Gt(v, _) => v,
+            Expression::Lt(v, _) => v,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    condition: Option<Expression>,
+    result: Outcome,
+}
+
+impl FromStr for Rule {
+    type Err = AocError;
+
+    fn from_str(rs: &str) -> Result<Rule, AocError> {
+        let (condition, result) = if let Some((expr_slice, res_slice)) = rs.split_once(':') {
+            (Some(expr_slice.parse()?), Outcome::from(res_slice))
+        } else {
+            (None, Outcome::from(rs))
+        };
+
+        Ok(Rule { condition, result })
+    }
+}
+
+impl Rule {
+    fn applies(&self, part: &Part) -> bool {
+        match &self.condition {
+            Some(cond) => cond.check(part),
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl FromStr for Workflow {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<Workflow, AocError> {
+        let (name, rules) = line
+            .strip_suffix('}')
+            .and_then(|l| l.split_once('{'))
+            .ok_or_else(|| AocError::parse("expected a workflow like name{rules}"))?;
+        let rules: Vec<Rule> = rules.split(',').map(str::parse).collect::<Result<_, _>>()?;
+
+        // Parts fall through the rules, so the last one must always apply.
+        if rules.last().is_some_and(|r| r.condition.is_some()) {
+            return Err(AocError::parse(
+                "last rule of a workflow must be unconditional",
+            ));
+        }
+
+        Ok(Workflow {
+            name: String::from(name),
+            rules,
+        })
+    }
+}
+
+impl Workflow {
+    fn run(&self, part: &Part) -> Outcome {
+        self.rules
+            .iter()
+            .find(|r| r.applies(part))
+            .unwrap()
+            .result
+            .clone()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn new() -> Range {
+        Range {
+            start: 1,
+            end: 4001,
+        }
+    }
+
+    fn len(&self) -> u32 {
+        self.end - self.start
+    }
+
+    // Returns: "range-exp-acceptsp, range-exp-rejects"
+    fn split(&self, by_expr: &Expression) -> (Range, Range) {
+        match by_expr {
+            Expression::Gt(_, v) => (
+                Range {
+                    start: *v + 1,
+                    end: self.end,
+                },
+                Range {
+                    start: self.start,
+                    end: *v + 1,
+                },
+            ),
+            Expression::Lt(_, v) => (
+                Range {
+                    start: self.start,
+                    end: *v,
+                },
+                Range {
+                    start: *v,
+                    end: self.end,
+                },
+            ),
+        }
+    }
+}
+
+pub type Part = HashMap<String, u32>;
+
+fn parse_part(l: &str) -> Result<Part, AocError> {
+    let part: Part = l
+        .strip_prefix('{')
+        .and_then(|l| l.strip_suffix('}'))
+        .ok_or_else(|| AocError::parse("expected a part like {x=1,m=2,a=3,s=4}"))?
+        .split(',')
+        .map(|part_val| {
+            let (var, value) = part_val
+                .split_once('=')
+                .ok_or_else(|| AocError::parse(format!("invalid rating {:?}", part_val)))?;
+            Ok((category(var)?, parse_num(value)?))
+        })
+        .collect::<Result<_, AocError>>()?;
+
+    if part.len() != CATEGORIES.len() {
+        return Err(AocError::parse("part must rate every category"));
+    }
+    Ok(part)
+}
+
+fn read_input(
+    lines: impl Iterator<Item = String>,
+) -> Result<(HashMap<String, Workflow>, Vec<Part>), AocError> {
+    let mut workflows = HashMap::new();
+    let mut parts = Vec::new();
+    let mut in_parts = false;
+
+    let read_span = span::enter("read lines");
+    for (idx, l) in lines.enumerate() {
+        if l.is_empty() {
+            in_parts = true;
+        } else if in_parts {
+            parts.push(parse_part(&l).map_err(|e| e.on_line(idx))?);
+        } else {
+            let w: Workflow = l.parse().map_err(|e: AocError| e.on_line(idx))?;
+            workflows.insert(String::from(&w.name), w);
+        }
+    }
+    drop(read_span);
+
+    let _check_span = span::enter("check targets");
+    if !workflows.contains_key("in") {
+        return Err(AocError::invalid("no \"in\" workflow"));
+    }
+    for w in workflows.values() {
+        for rule in w.rules.iter() {
+            if let Outcome::Goto(target) = &rule.result {
+                if !workflows.contains_key(target) {
+                    return Err(AocError::invalid(format!(
+                        "workflow {} sends parts to unknown workflow {}",
+                        w.name, target
+                    )));
+                }
+            }
+        }
+    }
+
+    Ok((workflows, parts))
+}
+
+fn part1(workflows: &HashMap<String, Workflow>, parts: &Vec<Part>) -> u32 {
+    let mut sum = 0;
+
+    for part in parts {
+        let mut step = Outcome::Goto(String::from("in"));
+        while let Outcome::Goto(ref step_name) = step {
+            step = workflows.get(step_name).unwrap().run(part)
+        }
+        if step == Outcome::Ok {
+            sum += part.values().sum::<u32>();
+        }
+    }
+
+    sum
+}
+
+fn part2(workflows: &HashMap<String, Workflow>) -> u64 {
+    let mut todo = VecDeque::new();
+    todo.push_back((
+        workflows.get(&String::from("in")).unwrap(),
+        HashMap::from([
+            (String::from("x"), Range::new()),
+            (String::from("m"), Range::new()),
+            (String::from("a"), Range::new()),
+            (String::from("s"), Range::new()),
+        ]),
+    ));
+
+    let _split_span = span::enter("split ranges");
+    let mut result_sum = 0u64;
+    while let Some((wf, mut ranges)) = todo.pop_front() {
+        for Rule { condition, result } in wf.rules.iter() {
+            // Reject needs separate treatment as it requires just a _substraction_ from the available ranges.
+            if *result == Outcome::Reject {
+                //Shrink remaining ranges.
+                if let Some(c) = condition {
+                    let old = ranges.get_mut(c.var()).unwrap();
+                    let (_, remains) = old.split(c);
+                    *old = remains
+                }
+            } else {
+                // for OK / Goto we need to first apply the rules & split
+                // Same for both cases.
+                let mut new_ranges = ranges.clone();
+                if let Some(c) = condition {
+                    let old = ranges.get_mut(c.var()).unwrap();
+                    let new = new_ranges.get_mut(c.var()).unwrap();
+                    let (applies, remains) = old.split(c);
+                    *old = remains;
+                    *new = applies;
+                }
+
+                // And then decide: add it to work queue, or add it to result vector.
+                if *result == Outcome::Ok {
+                    result_sum += new_ranges.values().map(|r| r.len() as u64).product::<u64>()
+                } else if let Outcome::Goto(wf_name) = result {
+                    todo.push_back((workflows.get(wf_name).unwrap(), new_ranges))
+                }
+            }
+        }
+    }
+
+    result_sum
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        read_input(lines)
+    }
+
+    fn part1((workflows, parts): &Self::Input) -> impl Display {
+        part1(workflows, parts)
+    }
+
+    fn part2((workflows, _): &Self::Input) -> impl Display {
+        part2(workflows)
+    }
+}
@@ -1,6 +1,9 @@
-use aoc2023::{read_lines, run_timed};
+use crate::progress::{Cancellable, Ticker};
+use crate::{parse_lines, AocError, Solution};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 use std::num::Wrapping;
+use std::str::FromStr;
 
 type SendPulse = (Pulse, String, String);
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -214,44 +217,72 @@ impl Module for Conjunction {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum ModuleKind {
+    BroadCast,
+    FlipFlop,
+    Conjunction,
+}
+
+// One line of the input: a module and the modules it sends pulses to.
+#[derive(Debug)]
+pub struct ModuleSpec {
+    kind: ModuleKind,
+    id: String,
+    outgoing: Vec<String>,
+}
+
+impl FromStr for ModuleSpec {
+    type Err = AocError;
+
+    fn from_str(line: &str) -> Result<ModuleSpec, AocError> {
+        let (id_part, connect_to) = line
+            .split_once(" -> ")
+            .ok_or_else(|| AocError::parse("missing ' -> ' after module"))?;
+        let (kind, id) = if id_part == "broadcaster" {
+            (ModuleKind::BroadCast, id_part)
+        } else if let Some(id) = id_part.strip_prefix('&') {
+            (ModuleKind::Conjunction, id)
+        } else if let Some(id) = id_part.strip_prefix('%') {
+            (ModuleKind::FlipFlop, id)
+        } else {
+            return Err(AocError::parse(format!("invalid module {:?}", id_part)));
+        };
+
+        Ok(ModuleSpec {
+            kind,
+            id: String::from(id),
+            outgoing: connect_to.split(", ").map(String::from).collect(),
+        })
+    }
+}
+
 struct Circuit {
     modules: HashMap<String, Box<dyn Module>>,
 }
 
 impl Circuit {
-    fn from_file(file_name: &str) -> Circuit {
+    fn new(specs: &[ModuleSpec]) -> Circuit {
         let mut modules: HashMap<String, Box<dyn Module>> = HashMap::new();
         modules.insert("rx".to_string(), Box::new(LowCounter::new()));
 
-        let all_lines: Vec<String> = read_lines(file_name).collect();
         //first add all modules
-        for line in all_lines.iter() {
-            let (id_part, _) = line.split_once(" -> ").unwrap();
-            if id_part == "broadcaster" {
-                modules.insert("broadcaster".to_string(), Box::new(BroadCast::new()));
-            } else {
-                let id = id_part[1..].to_string();
-                let module: Box<dyn Module> = match &id_part[0..1] {
-                    "&" => Box::new(Conjunction::new(id.clone())),
-                    "%" => Box::new(FlipFlop::new(id.clone())),
-                    other => panic!("Invalid type identifier: {}", other),
-                };
-                modules.insert(id, module);
-            }
-        }
-
-        for line in all_lines.iter() {
-            let (id_parts, connect_to) = line.split_once(" -> ").unwrap();
-            let id = match id_parts {
-                "broadcaster" => "broadcaster",
-                other => &other[1..],
+        for spec in specs {
+            let id = spec.id.clone();
+            let module: Box<dyn Module> = match spec.kind {
+                ModuleKind::BroadCast => Box::new(BroadCast::new()),
+                ModuleKind::Conjunction => Box::new(Conjunction::new(id.clone())),
+                ModuleKind::FlipFlop => Box::new(FlipFlop::new(id.clone())),
             };
+            modules.insert(id, module);
+        }
 
-            for c in connect_to.split(", ") {
+        for spec in specs {
+            for c in spec.outgoing.iter() {
                 if let Some(m) = modules.get_mut(c) {
-                    m.add_incoming(id);
+                    m.add_incoming(&spec.id);
                 }
-                modules.get_mut(id).unwrap().add_outgoing(c);
+                modules.get_mut(&spec.id).unwrap().add_outgoing(c);
             }
         }
 
@@ -271,46 +302,50 @@ impl Circuit {
     }
 }
 
-fn main() {
-    println!(
-        "Part 1: {}",
-        run_timed(|| {
-            let mut circuit = Circuit::from_file("./inputs/day20");
+pub struct Day20;
 
-            for _ in 0..1000 {
-                circuit.process_press();
-            }
+impl Solution for Day20 {
+    // Both parts need a fresh circuit, so keep the description around.
+    type Input = Vec<ModuleSpec>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        let specs: Vec<ModuleSpec> = parse_lines(lines, str::parse)?;
+        if !specs.iter().any(|s| s.kind == ModuleKind::BroadCast) {
+            return Err(AocError::invalid("circuit has no broadcaster"));
+        }
+        Ok(specs)
+    }
 
-            let (low_total, high_total) = circuit
-                .modules
-                .values()
-                .into_iter()
-                .fold((0, 0), |(low, high), m| {
-                    (low + m.low_count(), high + m.high_count())
-                });
+    fn part1(input: &Self::Input) -> impl Display {
+        let mut circuit = Circuit::new(input);
 
-            low_total * high_total
-        })
-    );
+        for _ in 0..1000 {
+            circuit.process_press();
+        }
+
+        let (low_total, high_total) = circuit.modules.values().fold((0, 0), |(low, high), m| {
+            (low + m.low_count(), high + m.high_count())
+        });
+
+        low_total * high_total
+    }
 
     /* This runs for hours, needs a better way
     (i.e.: cycle length detection in subgraphs & then compute other cycles from there)
     */
-    println!(
-        "Part 2: {}",
-        run_timed(|| {
-            let mut circuit = Circuit::from_file("./inputs/day20");
-            let mut press_count: u128 = 0;
-
-            while circuit.modules.get("rx").unwrap().low_count() < 1 {
-                circuit.process_press();
-                press_count += 1;
-                if press_count % 1_000_000 == 0 {
-                    println!("{}", press_count)
-                }
+    fn part2(input: &Self::Input) -> impl Display {
+        let mut circuit = Circuit::new(input);
+        let mut press_count: u128 = 0;
+        let ticker = Ticker::start("presses", None);
+
+        while circuit.modules.get("rx").unwrap().low_count() < 1 {
+            if !ticker.tick() {
+                return Cancellable::Cancelled;
             }
+            circuit.process_press();
+            press_count += 1;
+        }
 
-            press_count
-        })
-    )
+        Cancellable::Done(press_count)
+    }
 }
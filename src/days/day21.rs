@@ -0,0 +1,100 @@
+use crate::geometry::UPoint;
+use crate::progress::{Cancellable, Ticker};
+use crate::{AocError, Grid, Solution, Unsolved};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum MapTile {
+    Rock,
+    Garden,
+    Start,
+}
+
+impl TryFrom<char> for MapTile {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<MapTile, AocError> {
+        match c {
+            '.' => Ok(MapTile::Garden),
+            'S' => Ok(MapTile::Start),
+            '#' => Ok(MapTile::Rock),
+            _ => Err(AocError::parse(format!("invalid map tile {:?}", c))),
+        }
+    }
+}
+
+pub struct Garden(Grid<MapTile>);
+
+impl Garden {
+    fn from_lines(lines: impl Iterator<Item = String>) -> Result<(UPoint, Garden), AocError> {
+        let garden = Garden(Grid::parse(lines, MapTile::try_from)?);
+        let start = garden
+            .0
+            .find(|tile| *tile == MapTile::Start)
+            .ok_or_else(|| AocError::invalid("garden has no starting position"))?;
+        Ok((start, garden))
+    }
+
+    fn valid_steps(&self, point: UPoint) -> impl Iterator<Item = UPoint> + '_ {
+        self.0
+            .neighbours4(point)
+            .filter(|p| self.0[*p] != MapTile::Rock)
+    }
+}
+
+fn find_reachable(garden: &Garden, start: UPoint, steps_target: u32) -> Cancellable<u32> {
+    let mut todo = VecDeque::new();
+    todo.push_back((start, 0));
+
+    let mut reachable = HashSet::new();
+    let mut visited = HashSet::new();
+    let ticker = Ticker::start("steps", Some(steps_target as u64));
+    let mut layer = 0;
+
+    while let Some((point, steps_taken)) = todo.pop_front() {
+        // The queue holds one layer of steps after the other, count a step when a new layer starts.
+        if steps_taken > layer {
+            layer = steps_taken;
+            if !ticker.tick() {
+                return Cancellable::Cancelled;
+            }
+        }
+        if reachable.contains(&point) {
+            continue;
+        }
+        if visited.contains(&point) && steps_target.is_multiple_of(steps_taken)
+            || steps_taken == steps_target
+        {
+            reachable.insert(point);
+            continue;
+        }
+
+        for next in garden.valid_steps(point) {
+            todo.push_back((next, steps_taken + 1));
+        }
+        visited.insert(point);
+    }
+
+    Cancellable::Done(reachable.len() as u32)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (UPoint, Garden);
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        Garden::from_lines(lines)
+    }
+
+    fn part1((start, garden): &Self::Input) -> impl Display {
+        // The puzzle asks for 64 steps, its 11x11 example for only 6.
+        let steps = if garden.0.width() <= 11 { 6 } else { 64 };
+        find_reachable(garden, *start, steps)
+    }
+
+    fn part2(_input: &Self::Input) -> impl Display {
+        Unsolved
+    }
+}
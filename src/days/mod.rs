@@ -0,0 +1,146 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+
+use crate::{AocError, Part, Solution};
+use std::any::Any;
+
+/// A day's parsed input, with its type erased so the runner can hold any day's input.
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// A puzzle solution the runner can dispatch to.
+pub struct Day {
+    pub number: u8,
+    source: &'static str,
+    parse: fn(Vec<String>) -> Result<ParsedInput, AocError>,
+    solve: fn(&ParsedInput, Part) -> String,
+    variants: fn(Part) -> Vec<&'static str>,
+    solve_variant: fn(&ParsedInput, Part, &str) -> Option<String>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8, source: &'static str) -> Day
+    where
+        S::Input: Send + Sync + 'static,
+    {
+        Day {
+            number,
+            source,
+            parse: parse_erased::<S>,
+            solve: solve_erased::<S>,
+            variants: variants_erased::<S>,
+            solve_variant: solve_variant_erased::<S>,
+        }
+    }
+
+    /// The source file of the solution, to tell when it changed.
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    pub fn parse(&self, lines: Vec<String>) -> Result<ParsedInput, AocError> {
+        (self.parse)(lines)
+    }
+
+    /// Solves a part from input parsed by this same day; panics on another day's input.
+    pub fn solve(&self, input: &ParsedInput, part: Part) -> String {
+        (self.solve)(input, part)
+    }
+
+    pub fn variants(&self, part: Part) -> Vec<&'static str> {
+        (self.variants)(part)
+    }
+
+    /// Like [`Day::solve`], with the alternative implementation `name`; `None` when there is none.
+    pub fn solve_variant(&self, input: &ParsedInput, part: Part, name: &str) -> Option<String> {
+        (self.solve_variant)(input, part, name)
+    }
+}
+
+fn parse_erased<S: Solution>(lines: Vec<String>) -> Result<ParsedInput, AocError>
+where
+    S::Input: Send + Sync + 'static,
+{
+    Ok(Box::new(S::parse(lines.into_iter())?))
+}
+
+fn solve_erased<S: Solution>(input: &ParsedInput, part: Part) -> String
+where
+    S::Input: 'static,
+{
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different day");
+    match part {
+        Part::One => S::part1(input).to_string(),
+        Part::Two => S::part2(input).to_string(),
+    }
+}
+
+fn variants_erased<S: Solution>(part: Part) -> Vec<&'static str> {
+    S::VARIANTS
+        .iter()
+        .filter(|v| v.part == part)
+        .map(|v| v.name)
+        .collect()
+}
+
+fn solve_variant_erased<S: Solution>(
+    input: &ParsedInput,
+    part: Part,
+    name: &str,
+) -> Option<String> {
+    let variant = S::VARIANTS
+        .iter()
+        .find(|v| v.part == part && v.name == name)?;
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different day");
+    Some((variant.solve)(input))
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1, include_str!("day01.rs")),
+    Day::new::<day02::Day02>(2, include_str!("day02.rs")),
+    Day::new::<day03::Day03>(3, include_str!("day03.rs")),
+    Day::new::<day04::Day04>(4, include_str!("day04.rs")),
+    Day::new::<day05::Day05>(5, include_str!("day05.rs")),
+    Day::new::<day06::Day06>(6, include_str!("day06.rs")),
+    Day::new::<day07::Day07>(7, include_str!("day07.rs")),
+    Day::new::<day08::Day08>(8, include_str!("day08.rs")),
+    Day::new::<day09::Day09>(9, include_str!("day09.rs")),
+    Day::new::<day10::Day10>(10, include_str!("day10.rs")),
+    Day::new::<day11::Day11>(11, include_str!("day11.rs")),
+    Day::new::<day12::Day12>(12, include_str!("day12.rs")),
+    Day::new::<day13::Day13>(13, include_str!("day13.rs")),
+    Day::new::<day14::Day14>(14, include_str!("day14.rs")),
+    Day::new::<day15::Day15>(15, include_str!("day15.rs")),
+    Day::new::<day16::Day16>(16, include_str!("day16.rs")),
+    Day::new::<day17::Day17>(17, include_str!("day17.rs")),
+    Day::new::<day18::Day18>(18, include_str!("day18.rs")),
+    Day::new::<day19::Day19>(19, include_str!("day19.rs")),
+    Day::new::<day20::Day20>(20, include_str!("day20.rs")),
+    Day::new::<day21::Day21>(21, include_str!("day21.rs")),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,94 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    /// The input text is malformed. `line` is 1-based, when known.
+    Parse {
+        line: Option<usize>,
+        msg: String,
+    },
+    /// The input parses, but does not describe a puzzle the solution can work with.
+    InvalidPuzzle(String),
+    Config(String),
+    Server(String),
+}
+
+impl AocError {
+    pub fn parse<S: Into<String>>(msg: S) -> AocError {
+        AocError::Parse {
+            line: None,
+            msg: msg.into(),
+        }
+    }
+
+    pub fn invalid<S: Into<String>>(msg: S) -> AocError {
+        AocError::InvalidPuzzle(msg.into())
+    }
+
+    /// Attaches a line number to a parse error, unless it already has one.
+    pub fn on_line(self, line_idx: usize) -> AocError {
+        match self {
+            AocError::Parse { line: None, msg } => AocError::Parse {
+                line: Some(line_idx + 1),
+                msg,
+            },
+            other => other,
+        }
+    }
+
+    /// Moves the line number of a parse error down by `lines`, for errors from parsing a later
+    /// section of the input on its own.
+    pub fn shift_lines(self, lines: usize) -> AocError {
+        match self {
+            AocError::Parse {
+                line: Some(line),
+                msg,
+            } => AocError::Parse {
+                line: Some(line + lines),
+                msg,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "cannot read input: {}", e),
+            AocError::Parse {
+                line: Some(line),
+                msg,
+            } => write!(f, "parse error on line {}: {}", line, msg),
+            AocError::Parse { line: None, msg } => write!(f, "parse error: {}", msg),
+            AocError::InvalidPuzzle(msg) => write!(f, "invalid puzzle: {}", msg),
+            AocError::Config(msg) => write!(f, "configuration error: {}", msg),
+            AocError::Server(msg) => write!(f, "puzzle server: {}", msg),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(value: io::Error) -> Self {
+        AocError::Io(value)
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(value: ParseIntError) -> Self {
+        AocError::parse(format!("invalid number: {}", value))
+    }
+}
@@ -0,0 +1,234 @@
+use crate::html;
+use crate::{example_path, Answers, AocError, InputSource, Part, DEFAULT_EXAMPLE};
+use std::fs;
+use std::path::PathBuf;
+
+/// An example input from a puzzle description, with the answers the description gives for it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    /// The name `--example` picks it by: `test`, then `test2` for a second example.
+    pub name: String,
+    pub input: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Finds the examples in a saved puzzle page, which has an `<article>` per part.
+///
+/// A part's example is the first `<pre><code>` block introduced by a paragraph saying "for example".
+/// Part 1 falls back to its first block; part 2 reuses part 1's example when it has none of its own.
+/// The answer of a part is the last emphasised `<code><em>` in its article.
+/// Fails when an example block is not closed, as in a page that was not saved completely.
+pub fn extract_examples(page: &str) -> Result<Vec<Example>, AocError> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (article, part) in html::elements(page, "article").into_iter().zip(Part::ALL) {
+        let answer = emphasised_answer(article).map(|answer| (part, answer));
+        let input = example_block(article, part == Part::One)?;
+
+        match input {
+            Some(input) if examples.last().is_none_or(|e| e.input != input) => {
+                let name = match examples.len() {
+                    0 => String::from(DEFAULT_EXAMPLE),
+                    n => format!("{}{}", DEFAULT_EXAMPLE, n + 1),
+                };
+                examples.push(Example {
+                    name,
+                    input,
+                    answers: answer.into_iter().collect(),
+                });
+            }
+            _ => {
+                if let Some(last) = examples.last_mut() {
+                    last.answers.extend(answer);
+                }
+            }
+        }
+    }
+    Ok(examples)
+}
+
+fn example_block(article: &str, fall_back_to_first: bool) -> Result<Option<String>, AocError> {
+    let mut first = None;
+    let mut previous_end = 0;
+    while let Some(offset) = article[previous_end..].find("<pre><code>") {
+        let start = previous_end + offset;
+        let block = *html::elements(&article[start..], "code")
+            .first()
+            .ok_or_else(|| AocError::parse("example block without a closing </code>"))?;
+        let before = &article[previous_end..start];
+        let paragraph = before.rsplit("<p>").next().unwrap_or(before);
+        if html::text(paragraph).to_lowercase().contains("for example") {
+            return Ok(Some(html::text(block)));
+        }
+        first.get_or_insert_with(|| html::text(block));
+        previous_end = start + "<pre><code>".len() + block.len();
+    }
+    Ok(first.filter(|_| fall_back_to_first))
+}
+
+fn emphasised_answer(article: &str) -> Option<String> {
+    html::elements(article, "code")
+        .into_iter()
+        .filter_map(|code| code.strip_prefix("<em>")?.strip_suffix("</em>"))
+        .map(html::text)
+        .next_back()
+}
+
+/// What [`save_example`] did with an example.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Saved {
+    Written(PathBuf),
+    /// The same fixture was already there.
+    Unchanged(PathBuf),
+    /// A different fixture was already there, and was kept.
+    Kept(PathBuf),
+}
+
+/// Writes `example` as the input `dayNN_<name>` next to the real inputs, with its answers in the
+/// answers file for that input. An existing, different fixture is only replaced when `force` is set.
+pub fn save_example(day: u8, example: &Example, force: bool) -> Result<Saved, AocError> {
+    let path = example_path(day, &example.name);
+    let source = InputSource::from(path.as_path());
+    let mut answers = Answers::load(&source)?;
+
+    // An empty file, like the one `aoc new` leaves, is only a placeholder.
+    let existing = fs::read_to_string(&path).ok().filter(|e| !e.is_empty());
+    let same_input = existing
+        .as_deref()
+        .is_some_and(|e| e.trim_end() == example.input.trim_end());
+    let same_answers = example
+        .answers
+        .iter()
+        .all(|(part, answer)| answers.answer(*part) == Some(answer));
+    if same_input && same_answers {
+        return Ok(Saved::Unchanged(path));
+    }
+    let conflicting_answer = example
+        .answers
+        .iter()
+        .any(|(part, answer)| answers.answer(*part).is_some_and(|a| a != answer));
+    if ((existing.is_some() && !same_input) || conflicting_answer) && !force {
+        return Ok(Saved::Kept(path));
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    if !same_input {
+        fs::write(&path, &example.input)?;
+    }
+    for (part, answer) in &example.answers {
+        answers.set_answer(*part, answer);
+    }
+    answers.save()?;
+    Ok(Saved::Written(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{set_env, TempDir};
+    use crate::INPUT_DIR_VAR;
+
+    const PAGE: &str = "<main>
+<article class=\"day-desc\"><h2>--- Day 9: Mirage Maintenance ---</h2>
+<p>Your puzzle input looks like <code>this</code>:</p>
+<pre><code>not the example\n</code></pre>
+<p>For example:</p>
+<pre><code>0 3 6 9 12 15
+1 3 6 10 15 21
+</code></pre>
+<p>The sum of these is <code><em>114</em></code>.</p>
+</article>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>Adding the new values gives <code><em>2</em></code>.</p>
+</article>
+</main>";
+
+    #[test]
+    fn finds_the_example_and_answers() {
+        let examples = extract_examples(PAGE).unwrap();
+        assert_eq!(
+            examples,
+            [Example {
+                name: String::from("test"),
+                input: String::from("0 3 6 9 12 15\n1 3 6 10 15 21\n"),
+                answers: vec![
+                    (Part::One, String::from("114")),
+                    (Part::Two, String::from("2"))
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn names_a_second_example() {
+        let page = PAGE.replace(
+            "<p>Adding",
+            "<p>For example:</p><pre><code>10 13 16\n</code></pre><p>Adding",
+        );
+        let examples = extract_examples(&page).unwrap();
+        let names: Vec<&str> = examples.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["test", "test2"]);
+        assert_eq!(examples[1].answers, [(Part::Two, String::from("2"))]);
+    }
+
+    #[test]
+    fn rejects_an_unclosed_block() {
+        let page = "<article><p>For example:</p><pre><code>1 2 3\n</pre></article>";
+        let err = extract_examples(page).unwrap_err();
+        assert!(matches!(err, AocError::Parse { .. }), "{:?}", err);
+    }
+
+    fn example() -> Example {
+        Example {
+            name: String::from("test"),
+            input: String::from("1 2 3\n"),
+            answers: vec![(Part::One, String::from("6"))],
+        }
+    }
+
+    #[test]
+    fn fills_in_an_empty_placeholder() {
+        let dir = TempDir::new("examples");
+        let inputs = dir.path().join("inputs");
+        let _env = set_env(&[(INPUT_DIR_VAR, &inputs)]);
+        fs::create_dir_all(&inputs).unwrap();
+        let path = inputs.join("day09_test");
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(
+            save_example(9, &example(), false).unwrap(),
+            Saved::Written(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("answers").join("day09_test.toml")).unwrap(),
+            "part1 = \"6\"\n"
+        );
+        assert_eq!(
+            save_example(9, &example(), false).unwrap(),
+            Saved::Unchanged(path)
+        );
+    }
+
+    #[test]
+    fn keeps_a_different_fixture_unless_forced() {
+        let dir = TempDir::new("examples");
+        let inputs = dir.path().join("inputs");
+        let _env = set_env(&[(INPUT_DIR_VAR, &inputs)]);
+        fs::create_dir_all(&inputs).unwrap();
+        let path = inputs.join("day09_test");
+        fs::write(&path, "4 5 6\n").unwrap();
+
+        assert_eq!(
+            save_example(9, &example(), false).unwrap(),
+            Saved::Kept(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "4 5 6\n");
+        assert_eq!(
+            save_example(9, &example(), true).unwrap(),
+            Saved::Written(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+    }
+}
@@ -0,0 +1,169 @@
+use crate::http;
+use crate::{day_path, AocError, Config};
+use std::fs;
+use std::path::PathBuf;
+
+/// Where [`fetch_input`] found a day's input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Fetched {
+    /// The input was already on disk, so nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Makes sure the input for `day` is in the inputs directory, downloading it when it is not.
+///
+/// An input is downloaded at most once: any non-empty file already there is kept as it is.
+pub fn fetch_input(config: &Config, day: u8) -> Result<Fetched, AocError> {
+    let path = day_path(day);
+    if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let url = format!("{}/{}/day/{}/input", config.base_url, config.year, day);
+    let cookie = format!("session={}", config.session()?);
+    let headers = [
+        ("Cookie", cookie.as_str()),
+        ("User-Agent", config.user_agent.as_str()),
+    ];
+    let response = http::request("GET", &url, &headers, None)?;
+    if !response.is_success() {
+        return Err(AocError::Server(format!(
+            "GET {} answered {}: {}",
+            url,
+            response.status,
+            response.body.trim()
+        )));
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // Written next to its final place first, so an interrupted download never looks cached.
+    let partial = path.with_extension("partial");
+    fs::write(&partial, &response.body)?;
+    fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Downloads the JSON of the private leaderboard `id`. Unlike inputs, it changes, so it is not cached.
+pub fn fetch_leaderboard(config: &Config, id: u64) -> Result<String, AocError> {
+    let url = format!(
+        "{}/{}/leaderboard/private/view/{}.json",
+        config.base_url, config.year, id
+    );
+    let cookie = format!("session={}", config.session()?);
+    let headers = [
+        ("Cookie", cookie.as_str()),
+        ("User-Agent", config.user_agent.as_str()),
+    ];
+    let response = http::request("GET", &url, &headers, None)?;
+    if !response.is_success() {
+        return Err(AocError::Server(format!(
+            "GET {} answered {}",
+            url, response.status
+        )));
+    }
+    Ok(response.body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{set_env, StubServer, TempDir};
+    use crate::INPUT_DIR_VAR;
+
+    fn config(server: &StubServer, session: Option<&str>) -> Config {
+        Config {
+            session: session.map(String::from),
+            base_url: String::from(server.url()),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn downloads_a_missing_input() {
+        let dir = TempDir::new("fetch");
+        let _env = set_env(&[(INPUT_DIR_VAR, dir.path())]);
+        let server = StubServer::start(vec![(200, "1 2 3\n4 5 6\n")]);
+
+        let fetched = fetch_input(&config(&server, Some("c0ffee")), 3).unwrap();
+        let path = dir.path().join("day03");
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n4 5 6\n");
+        assert!(!path.with_extension("partial").exists());
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/3/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=c0ffee\r\n"));
+        assert!(requests[0].contains("User-Agent: github.com/jdijt/adventofcode2023\r\n"));
+    }
+
+    #[test]
+    fn keeps_an_input_already_there() {
+        let dir = TempDir::new("fetch");
+        let _env = set_env(&[(INPUT_DIR_VAR, dir.path())]);
+        let path = dir.path().join("day07");
+        fs::write(&path, "32T3K 765\n").unwrap();
+        let server = StubServer::start(vec![(200, "something else\n")]);
+
+        let fetched = fetch_input(&config(&server, Some("c0ffee")), 7).unwrap();
+        assert_eq!(fetched, Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "32T3K 765\n");
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn downloads_over_an_empty_file() {
+        let dir = TempDir::new("fetch");
+        let _env = set_env(&[(INPUT_DIR_VAR, dir.path())]);
+        fs::write(dir.path().join("day07"), "").unwrap();
+        let server = StubServer::start(vec![(200, "32T3K 765\n")]);
+
+        let fetched = fetch_input(&config(&server, Some("c0ffee")), 7).unwrap();
+        assert!(matches!(fetched, Fetched::Downloaded(_)));
+    }
+
+    #[test]
+    fn reports_a_missing_puzzle() {
+        let dir = TempDir::new("fetch");
+        let _env = set_env(&[(INPUT_DIR_VAR, dir.path())]);
+        let server = StubServer::start(vec![(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!\n",
+        )]);
+
+        let err = fetch_input(&config(&server, Some("c0ffee")), 25).unwrap_err();
+        assert!(matches!(err, AocError::Server(_)), "{:?}", err);
+        assert!(err.to_string().contains("answered 404"), "{}", err);
+        assert!(err.to_string().contains("before it unlocks"), "{}", err);
+        assert!(!dir.path().join("day25").exists());
+    }
+
+    #[test]
+    fn reports_a_rejected_session() {
+        let dir = TempDir::new("fetch");
+        let _env = set_env(&[(INPUT_DIR_VAR, dir.path())]);
+        let server = StubServer::start(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        )]);
+
+        let err = fetch_input(&config(&server, Some("expired")), 1).unwrap_err();
+        assert!(err.to_string().contains("answered 400"), "{}", err);
+        assert!(err.to_string().contains("Please log in"), "{}", err);
+        assert!(!dir.path().join("day01").exists());
+    }
+
+    #[test]
+    fn needs_a_session_before_asking() {
+        let dir = TempDir::new("fetch");
+        let _env = set_env(&[(INPUT_DIR_VAR, dir.path())]);
+        let server = StubServer::start(vec![(200, "never sent\n")]);
+
+        let err = fetch_input(&config(&server, None), 1).unwrap_err();
+        assert!(matches!(err, AocError::Config(_)), "{:?}", err);
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,1029 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Makes random, valid inputs for one day's puzzle, to test and benchmark beyond the official input.
+pub struct Generator {
+    pub day: u8,
+    /// What the size means for this day, e.g. `rows` or `grid side`.
+    pub size_unit: &'static str,
+    pub default_size: usize,
+    /// The range of sizes the day's solutions handle; sizes outside it are clamped.
+    pub sizes: (usize, usize),
+    generate: fn(&mut Rng, usize) -> Vec<String>,
+}
+
+impl Generator {
+    /// The input for `seed` and `size`: the same seed and size always give the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let size = size.clamp(self.sizes.0, self.sizes.1);
+        let mut lines = (self.generate)(&mut Rng::new(seed), size);
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size_unit: "lines",
+        default_size: 1000,
+        sizes: (1, usize::MAX),
+        generate: day01,
+    },
+    Generator {
+        day: 2,
+        size_unit: "games",
+        default_size: 100,
+        sizes: (1, usize::MAX),
+        generate: day02,
+    },
+    Generator {
+        day: 3,
+        size_unit: "grid side",
+        default_size: 140,
+        sizes: (3, usize::MAX),
+        generate: day03,
+    },
+    Generator {
+        day: 4,
+        size_unit: "cards",
+        default_size: 200,
+        sizes: (1, usize::MAX),
+        generate: day04,
+    },
+    Generator {
+        day: 5,
+        size_unit: "seed ranges",
+        default_size: 10,
+        sizes: (1, usize::MAX),
+        generate: day05,
+    },
+    // Part 2 reads all times as one number, which has to fit in a u64 and be scanned.
+    Generator {
+        day: 6,
+        size_unit: "races",
+        default_size: 4,
+        sizes: (1, 4),
+        generate: day06,
+    },
+    Generator {
+        day: 7,
+        size_unit: "hands",
+        default_size: 1000,
+        sizes: (1, 100_000),
+        generate: day07,
+    },
+    Generator {
+        day: 8,
+        size_unit: "nodes, roughly",
+        default_size: 750,
+        sizes: (1, usize::MAX),
+        generate: day08,
+    },
+    Generator {
+        day: 9,
+        size_unit: "sequences",
+        default_size: 200,
+        sizes: (1, usize::MAX),
+        generate: day09,
+    },
+    Generator {
+        day: 10,
+        size_unit: "grid side",
+        default_size: 140,
+        sizes: (4, usize::MAX),
+        generate: day10,
+    },
+    Generator {
+        day: 11,
+        size_unit: "grid side",
+        default_size: 140,
+        sizes: (2, usize::MAX),
+        generate: day11,
+    },
+    Generator {
+        day: 12,
+        size_unit: "rows",
+        default_size: 1000,
+        sizes: (1, usize::MAX),
+        generate: day12,
+    },
+    Generator {
+        day: 13,
+        size_unit: "patterns",
+        default_size: 100,
+        sizes: (1, usize::MAX),
+        generate: day13,
+    },
+    Generator {
+        day: 14,
+        size_unit: "grid side",
+        default_size: 100,
+        sizes: (1, usize::MAX),
+        generate: day14,
+    },
+    Generator {
+        day: 15,
+        size_unit: "steps",
+        default_size: 4000,
+        sizes: (1, usize::MAX),
+        generate: day15,
+    },
+    Generator {
+        day: 16,
+        size_unit: "grid side",
+        default_size: 110,
+        sizes: (1, usize::MAX),
+        generate: day16,
+    },
+    // Part 2 needs room for runs of at least four blocks.
+    Generator {
+        day: 17,
+        size_unit: "grid side",
+        default_size: 141,
+        sizes: (12, usize::MAX),
+        generate: day17,
+    },
+    Generator {
+        day: 18,
+        size_unit: "columns of the lagoon",
+        default_size: 200,
+        sizes: (1, usize::MAX),
+        generate: day18,
+    },
+    Generator {
+        day: 19,
+        size_unit: "workflows",
+        default_size: 550,
+        sizes: (1, usize::MAX),
+        generate: day19,
+    },
+    // Part 2 presses the button until the counters line up, about 2^(4 * bits) times: the 12 bits
+    // of the official input are far too many.
+    Generator {
+        day: 20,
+        size_unit: "bits per counter",
+        default_size: 5,
+        sizes: (2, 5),
+        generate: day20,
+    },
+    Generator {
+        day: 21,
+        size_unit: "grid side",
+        default_size: 131,
+        sizes: (5, usize::MAX),
+        generate: day21,
+    },
+];
+
+pub fn generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+// SplitMix64: small, fast, and the same on every platform, so seeds reproduce.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn range(&mut self, lo: usize, hi: usize) -> usize {
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as usize
+    }
+
+    fn signed(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i64
+    }
+
+    fn fraction(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn chance(&mut self, p: f64) -> bool {
+        self.fraction() < p
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.range(0, items.len() - 1)]
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i));
+        }
+    }
+
+    fn distinct(&mut self, count: usize, lo: usize, hi: usize) -> Vec<usize> {
+        let mut all: Vec<usize> = (lo..=hi).collect();
+        self.shuffle(&mut all);
+        all.truncate(count);
+        all
+    }
+
+    fn grid(&mut self, width: usize, height: usize, tiles: &[(char, f64)]) -> Vec<Vec<char>> {
+        (0..height)
+            .map(|_| (0..width).map(|_| self.tile(tiles)).collect())
+            .collect()
+    }
+
+    fn tile(&mut self, tiles: &[(char, f64)]) -> char {
+        let mut roll = self.fraction();
+        for (tile, p) in tiles {
+            if roll < *p {
+                return *tile;
+            }
+            roll -= p;
+        }
+        tiles[0].0
+    }
+}
+
+fn lines(grid: &[Vec<char>]) -> Vec<String> {
+    grid.iter().map(|row| row.iter().collect()).collect()
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn day01(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let target = rng.range(4, 40);
+            let mut line = String::new();
+            while line.len() < target {
+                match rng.range(0, 9) {
+                    0 => line.push(char::from(b'0' + rng.range(1, 9) as u8)),
+                    1 => line.push_str(rng.pick(&DIGIT_WORDS)),
+                    _ => line.push(char::from(b'a' + rng.range(0, 25) as u8)),
+                }
+            }
+            // Part 1 needs a digit on every line.
+            if !line.contains(|c: char| c.is_ascii_digit()) {
+                let at = rng.range(0, line.len());
+                line.insert(at, char::from(b'0' + rng.range(1, 9) as u8));
+            }
+            line
+        })
+        .collect()
+}
+
+fn day02(rng: &mut Rng, size: usize) -> Vec<String> {
+    (1..=size)
+        .map(|id| {
+            let reveals: Vec<String> = (0..rng.range(1, 6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    let shown = rng.range(1, 3);
+                    let counts: Vec<String> = colors[..shown]
+                        .iter()
+                        .map(|color| format!("{} {}", rng.range(1, 20), color))
+                        .collect();
+                    counts.join(", ")
+                })
+                .collect();
+            format!("Game {}: {}", id, reveals.join("; "))
+        })
+        .collect()
+}
+
+fn day03(rng: &mut Rng, size: usize) -> Vec<String> {
+    const SYMBOLS: [char; 10] = ['*', '*', '*', '#', '+', '$', '/', '=', '%', '@'];
+    let mut grid = vec![vec!['.'; size]; size];
+    for row in grid.iter_mut() {
+        let mut x = 0;
+        while x < size {
+            let digits = rng.range(1, 3);
+            if x + digits <= size && rng.chance(0.15) {
+                let number = rng.range(
+                    10usize.pow(digits as u32 - 1),
+                    10usize.pow(digits as u32) - 1,
+                );
+                for (cell, digit) in row[x..].iter_mut().zip(number.to_string().chars()) {
+                    *cell = digit;
+                }
+                // Numbers need a cell between them, or they would read as one.
+                x += digits + 1;
+            } else {
+                if rng.chance(0.06) {
+                    row[x] = rng.pick(&SYMBOLS);
+                }
+                x += 1;
+            }
+        }
+    }
+    lines(&grid)
+}
+
+fn day04(rng: &mut Rng, size: usize) -> Vec<String> {
+    let width = size.to_string().len();
+    let numbers = |list: &[usize]| -> String {
+        let numbers: Vec<String> = list.iter().map(|n| format!("{:>2}", n)).collect();
+        numbers.join(" ")
+    };
+    (1..=size)
+        .map(|id| {
+            let winning = rng.distinct(10, 1, 99);
+            // Copies never run past the last card.
+            let matches = rng.range(0, 10.min(size - id));
+            let mut have: Vec<usize> = winning[..matches].to_vec();
+            let others: Vec<usize> = rng
+                .distinct(99, 1, 99)
+                .into_iter()
+                .filter(|n| !winning.contains(n))
+                .take(25 - matches)
+                .collect();
+            have.extend(others);
+            rng.shuffle(&mut have);
+            format!(
+                "Card {:>width$}: {} | {}",
+                id,
+                numbers(&winning),
+                numbers(&have)
+            )
+        })
+        .collect()
+}
+
+fn day05(rng: &mut Rng, size: usize) -> Vec<String> {
+    const SPACE: usize = 1 << 32;
+    const CATEGORIES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+
+    // Short seed ranges: the brute force variant of part 2 expands every seed.
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let len = rng.range(1, 100_000);
+            format!("{} {}", rng.range(0, SPACE - len), len)
+        })
+        .collect();
+    let mut out = vec![format!("seeds: {}", seeds.join(" "))];
+
+    for pair in CATEGORIES.windows(2) {
+        out.push(String::new());
+        out.push(format!("{}-to-{} map:", pair[0], pair[1]));
+        let mut cuts: Vec<usize> = (0..rng.range(8, 40))
+            .map(|_| rng.range(1, SPACE - 1))
+            .collect();
+        cuts.extend([0, SPACE]);
+        cuts.sort();
+        cuts.dedup();
+        let sources: Vec<(usize, usize)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+
+        // The mapped ranges land in a shuffled order, so every map moves most numbers.
+        let mut order: Vec<usize> = (0..sources.len()).collect();
+        rng.shuffle(&mut order);
+        let mut destination = 0;
+        let mut mappings = Vec::new();
+        for idx in order {
+            let (start, len) = sources[idx];
+            if rng.chance(0.85) {
+                mappings.push(format!("{} {} {}", destination, start, len));
+            }
+            destination += len;
+        }
+        rng.shuffle(&mut mappings);
+        out.extend(mappings);
+    }
+    out
+}
+
+fn day06(rng: &mut Rng, size: usize) -> Vec<String> {
+    let races: Vec<(usize, usize)> = (0..size)
+        .map(|_| {
+            let time = rng.range(7, 99);
+            // Holding the button for half the race goes furthest; the record is below that.
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(time, best - 1))
+        })
+        .collect();
+    let row = |label: &str, values: Vec<usize>| -> String {
+        let values: Vec<String> = values.iter().map(|v| format!("{:>6}", v)).collect();
+        format!("{:<9}{}", label, values.concat())
+    };
+    vec![
+        row("Time:", races.iter().map(|r| r.0).collect()),
+        row("Distance:", races.iter().map(|r| r.1).collect()),
+    ]
+}
+
+fn day07(rng: &mut Rng, size: usize) -> Vec<String> {
+    const CARDS: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+    let mut seen = HashSet::new();
+    let mut hands = Vec::new();
+    while hands.len() < size {
+        // Pairs and triples are far more likely than with fully random cards.
+        let pool: Vec<char> = (0..rng.range(2, 5)).map(|_| rng.pick(&CARDS)).collect();
+        let hand: String = (0..5).map(|_| rng.pick(&pool)).collect();
+        if seen.insert(hand.clone()) {
+            hands.push(format!("{} {}", hand, rng.range(1, 1000)));
+        }
+    }
+    hands
+}
+
+// Node names of at least three letters, never ending in A or Z.
+fn node_name(idx: usize) -> String {
+    let mut name = vec![char::from(b'B' + (idx % 24) as u8)];
+    let mut rest = idx / 24;
+    while name.len() < 3 || rest > 0 {
+        name.push(char::from(b'A' + (rest % 26) as u8));
+        rest /= 26;
+    }
+    name.iter().rev().collect()
+}
+
+fn day08(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Every ghost walks a loop of layers, two nodes wide so the route matters. Each node leads to
+    // nodes of the next layer picked for it alone, so whichever way the route turns a ghost ends
+    // its loop after as many steps as the loop has layers. The first ghost walks from AAA to ZZZ.
+    // Loop lengths are primes picked by the seed times a shared factor, like the official input,
+    // which keeps the search for the first step all ghosts end together short.
+    let mut primes = [3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let primes = &primes[..6];
+    let factor = (size / (2 * primes.iter().sum::<usize>())).max(1);
+    let route: String = (0..rng.range(20, 300))
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let mut names = 0;
+    let mut nodes = Vec::new();
+    let pair = |rng: &mut Rng, a: &str, b: &str| -> String {
+        match rng.chance(0.5) {
+            true => format!("({}, {})", a, b),
+            false => format!("({}, {})", b, a),
+        }
+    };
+    for (ghost, prime) in primes.iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => (String::from("AAA"), String::from("ZZZ")),
+            _ => {
+                let prefix = char::from(b'A' + ghost as u8).to_string().repeat(2);
+                (format!("{}A", prefix), format!("{}Z", prefix))
+            }
+        };
+        let layers: Vec<[String; 2]> = (1..prime * factor)
+            .map(|_| {
+                names += 2;
+                [node_name(names - 2), node_name(names - 1)]
+            })
+            .collect();
+        nodes.push(format!(
+            "{} = {}",
+            start,
+            pair(rng, &layers[0][0], &layers[0][1])
+        ));
+        for (idx, layer) in layers.iter().enumerate() {
+            for node in layer {
+                let targets = match layers.get(idx + 1) {
+                    Some(next) => {
+                        let next = [next[0].as_str(), next[1].as_str()];
+                        format!("({}, {})", rng.pick(&next), rng.pick(&next))
+                    }
+                    None => format!("({}, {})", end, end),
+                };
+                nodes.push(format!("{} = {}", node, targets));
+            }
+        }
+        nodes.push(format!(
+            "{} = {}",
+            end,
+            pair(rng, &layers[0][0], &layers[0][1])
+        ));
+    }
+    rng.shuffle(&mut nodes);
+
+    let mut out = vec![route, String::new()];
+    out.extend(nodes);
+    out
+}
+
+fn day09(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            // A polynomial sequence: build its difference table up from a constant row.
+            let degree = rng.range(1, 6);
+            let mut row = vec![rng.signed(-5, 5); 21];
+            for _ in 0..degree {
+                let mut above = vec![rng.signed(-10, 10)];
+                for diff in &row[..20] {
+                    above.push(above.last().unwrap() + diff);
+                }
+                row = above;
+            }
+            let values: Vec<String> = row.iter().map(i64::to_string).collect();
+            values.join(" ")
+        })
+        .collect()
+}
+
+fn day10(rng: &mut Rng, size: usize) -> Vec<String> {
+    // The loop is the outline of a shape that is one run of cells in every column, with
+    // neighbouring runs overlapping. Such a shape has no holes and never touches itself at a
+    // corner, so its outline is a simple loop.
+    let (cols, rows) = (size - 1, size - 1);
+    let mut runs = Vec::new();
+    let bottom = rng.range(0, rows - 2);
+    let mut run = (bottom, rng.range(bottom + 2, rows));
+    for _ in 0..cols {
+        runs.push(run);
+        let (b, t) = run;
+        let nb = (b as i64 + rng.signed(-3, 3)).clamp(0, t as i64 - 1) as usize;
+        let nt = (t as i64 + rng.signed(-3, 3)).clamp(nb.max(b) as i64 + 1, rows as i64) as usize;
+        run = (nb, nt);
+    }
+    let inside = |c: i64, r: i64| {
+        c >= 0 && (c as usize) < cols && {
+            let (b, t) = runs[c as usize];
+            r >= b as i64 && r < t as i64
+        }
+    };
+
+    // Corners of the cells are tiles of the map.
+    let mut links: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+    let mut link = |a: (usize, usize), b: (usize, usize)| {
+        links.entry(a).or_default().push(b);
+        links.entry(b).or_default().push(a);
+    };
+    for (c, &(b, t)) in runs.iter().enumerate() {
+        for r in b..t {
+            let (ci, ri) = (c as i64, r as i64);
+            if !inside(ci, ri - 1) {
+                link((c, r), (c + 1, r));
+            }
+            if !inside(ci, ri + 1) {
+                link((c, r + 1), (c + 1, r + 1));
+            }
+            if !inside(ci - 1, ri) {
+                link((c, r), (c, r + 1));
+            }
+            if !inside(ci + 1, ri) {
+                link((c + 1, r), (c + 1, r + 1));
+            }
+        }
+    }
+
+    let mut grid = rng.grid(
+        size,
+        size,
+        &[
+            ('.', 0.4),
+            ('|', 0.1),
+            ('-', 0.1),
+            ('L', 0.1),
+            ('J', 0.1),
+            ('7', 0.1),
+            ('F', 0.1),
+        ],
+    );
+    for (&(x, y), ends) in &links {
+        let mut dirs: Vec<(i64, i64)> = ends
+            .iter()
+            .map(|&(ex, ey)| (ex as i64 - x as i64, ey as i64 - y as i64))
+            .collect();
+        dirs.sort();
+        grid[y][x] = match dirs[..] {
+            [(0, -1), (0, 1)] => '|',
+            [(-1, 0), (1, 0)] => '-',
+            [(0, -1), (1, 0)] => 'L',
+            [(-1, 0), (0, -1)] => 'J',
+            [(-1, 0), (0, 1)] => '7',
+            [(0, 1), (1, 0)] => 'F',
+            _ => unreachable!("the outline has two ends at every corner"),
+        };
+    }
+    // The start goes on the left edge, where the loop runs straight north to south: the
+    // solution counts it as a vertical pipe. Nothing else may connect to it.
+    let start = runs[0].0 + 1;
+    grid[start][0] = 'S';
+    grid[start][1] = match links.contains_key(&(1, start)) {
+        true => grid[start][1],
+        false => '.',
+    };
+    lines(&grid)
+}
+
+fn day11(rng: &mut Rng, size: usize) -> Vec<String> {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.08)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.08)).collect();
+    let mut grid = vec![vec!['.'; size]; size];
+    for (y, row) in grid.iter_mut().enumerate() {
+        for (x, cell) in row.iter_mut().enumerate() {
+            if !empty_rows[y] && !empty_cols[x] && rng.chance(0.02) {
+                *cell = '#';
+            }
+        }
+    }
+    // There must be a galaxy to measure from.
+    grid[0][0] = '#';
+    lines(&grid)
+}
+
+fn day12(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            // Lay out a real arrangement of damaged groups, then hide part of it.
+            let groups: Vec<usize> = (0..rng.range(1, 6)).map(|_| rng.range(1, 5)).collect();
+            let mut springs = String::new();
+            for (idx, group) in groups.iter().enumerate() {
+                let gap = match idx {
+                    0 => rng.range(0, 2),
+                    _ => rng.range(1, 3),
+                };
+                springs.push_str(&".".repeat(gap));
+                springs.push_str(&"#".repeat(*group));
+            }
+            springs.push_str(&".".repeat(rng.range(0, 2)));
+            let hidden: String = springs
+                .chars()
+                .map(|c| if rng.chance(0.5) { '?' } else { c })
+                .collect();
+            let groups: Vec<String> = groups.iter().map(usize::to_string).collect();
+            format!("{} {}", hidden, groups.join(","))
+        })
+        .collect()
+}
+
+fn day13(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut out = Vec::new();
+    for idx in 0..size {
+        if idx > 0 {
+            out.push(String::new());
+        }
+        out.extend(lines(&mirror_pattern(rng)));
+    }
+    out
+}
+
+// A pattern with one perfect line of reflection, and one other line that is off by one cell.
+fn mirror_pattern(rng: &mut Rng) -> Vec<Vec<char>> {
+    loop {
+        let (height, width) = (rng.range(5, 17), rng.range(5, 17));
+        let row_line = rng.range(1, height - 1);
+        if 2 * row_line == height {
+            continue;
+        }
+        let col_line = rng.range(1, width - 1);
+        let mut grid = rng.grid(width, height, &[('.', 0.5), ('#', 0.5)]);
+        for row in grid.iter_mut() {
+            for x in 0..col_line.min(width - col_line) {
+                row[col_line + x] = row[col_line - 1 - x];
+            }
+        }
+        let row_reach = row_line.min(height - row_line);
+        for y in 0..row_reach {
+            grid[row_line + y] = grid[row_line - 1 - y].clone();
+        }
+        // The smudge sits in a row without a mirror image, so the row reflection stays perfect.
+        let y = match row_line - row_reach {
+            0 => rng.range(row_line + row_reach, height - 1),
+            _ => rng.range(0, row_line - row_reach - 1),
+        };
+        let col_reach = col_line.min(width - col_line);
+        let x = rng.range(col_line - col_reach, col_line + col_reach - 1);
+        grid[y][x] = if grid[y][x] == '#' { '.' } else { '#' };
+
+        if reflections(&grid) == [(0, row_line, true), (1, col_line, false)] {
+            if rng.chance(0.5) {
+                let transposed = (0..width)
+                    .map(|x| grid.iter().map(|row| row[x]).collect())
+                    .collect();
+                return transposed;
+            }
+            return grid;
+        }
+    }
+}
+
+// Every line with at most one mismatched cell, as (mismatches, line, is a row line), best first.
+fn reflections(grid: &[Vec<char>]) -> Vec<(usize, usize, bool)> {
+    let (height, width) = (grid.len(), grid[0].len());
+    let mut found = Vec::new();
+    for line in 1..height {
+        let diff: usize = (0..line.min(height - line))
+            .map(|d| {
+                let (a, b) = (&grid[line - 1 - d], &grid[line + d]);
+                a.iter().zip(b).filter(|(l, r)| l != r).count()
+            })
+            .sum();
+        found.push((diff, line, true));
+    }
+    for line in 1..width {
+        let diff: usize = grid
+            .iter()
+            .map(|row| {
+                (0..line.min(width - line))
+                    .filter(|d| row[line - 1 - d] != row[line + d])
+                    .count()
+            })
+            .sum();
+        found.push((diff, line, false));
+    }
+    found.retain(|(diff, _, _)| *diff <= 1);
+    found.sort();
+    found
+}
+
+fn day14(rng: &mut Rng, size: usize) -> Vec<String> {
+    lines(&rng.grid(size, size, &[('.', 0.65), ('O', 0.2), ('#', 0.15)]))
+}
+
+fn day15(rng: &mut Rng, size: usize) -> Vec<String> {
+    let labels: Vec<String> = (0..(size / 4).max(1))
+        .map(|_| {
+            (0..rng.range(2, 6))
+                .map(|_| char::from(b'a' + rng.range(0, 25) as u8))
+                .collect()
+        })
+        .collect();
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = &labels[rng.range(0, labels.len() - 1)];
+            match rng.chance(0.6) {
+                true => format!("{}={}", label, rng.range(1, 9)),
+                false => format!("{}-", label),
+            }
+        })
+        .collect();
+    vec![steps.join(",")]
+}
+
+fn day16(rng: &mut Rng, size: usize) -> Vec<String> {
+    lines(&rng.grid(
+        size,
+        size,
+        &[
+            ('.', 0.9),
+            ('/', 0.025),
+            ('\\', 0.025),
+            ('-', 0.025),
+            ('|', 0.025),
+        ],
+    ))
+}
+
+fn day17(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.range(1, 9) as u8))
+                .collect()
+        })
+        .collect()
+}
+
+fn day18(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Both plans dig a skyline: columns of different heights on a flat bottom. Distances are even,
+    // so parallel trenches never run next to each other.
+    let skyline = |rng: &mut Rng, unit: usize| -> Vec<(char, usize)> {
+        let mut heights: Vec<usize> = Vec::new();
+        while heights.len() < size {
+            let height = rng.range(1, 10) * 2 * unit;
+            if heights.last() != Some(&height) {
+                heights.push(height);
+            }
+        }
+        let widths: Vec<usize> = (0..size).map(|_| rng.range(1, 10) * 2 * unit).collect();
+
+        let mut plan = vec![('U', heights[0])];
+        for idx in 0..size {
+            if idx > 0 {
+                let (from, to) = (heights[idx - 1], heights[idx]);
+                plan.push(if to > from {
+                    ('U', to - from)
+                } else {
+                    ('D', from - to)
+                });
+            }
+            plan.push(('R', widths[idx]));
+        }
+        plan.push(('D', heights[size - 1]));
+        plan.extend(widths.iter().rev().map(|w| ('L', *w)));
+        plan
+    };
+    let small = skyline(rng, 1);
+    let large = skyline(rng, 500);
+    small
+        .iter()
+        .zip(&large)
+        .map(|((dir, steps), (big_dir, big_steps))| {
+            let code = match big_dir {
+                'R' => 0,
+                'D' => 1,
+                'L' => 2,
+                _ => 3,
+            };
+            format!("{} {} (#{:05x}{})", dir, steps, big_steps, code)
+        })
+        .collect()
+}
+
+fn day19(rng: &mut Rng, size: usize) -> Vec<String> {
+    const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+    // A tree of workflows from "in". The ratings that can reach every workflow are tracked, so
+    // every condition splits them into two parts that are both possible.
+    let mut workflows = Vec::new();
+    let mut created = 1;
+    let mut todo = VecDeque::from([(String::from("in"), [(1usize, 4001usize); 4])]);
+    while let Some((name, mut ranges)) = todo.pop_front() {
+        let mut target = |rng: &mut Rng, ranges: [(usize, usize); 4], first: bool| {
+            if created < size && (first || rng.chance(0.6)) {
+                created += 1;
+                let child = workflow_name(created);
+                todo.push_back((child.clone(), ranges));
+                child
+            } else {
+                String::from(if rng.chance(0.5) { "A" } else { "R" })
+            }
+        };
+
+        let mut rules = Vec::new();
+        for idx in 0..rng.range(1, 3) {
+            let splittable: Vec<usize> = (0..4)
+                .filter(|c| ranges[*c].1 - ranges[*c].0 >= 3)
+                .collect();
+            if splittable.is_empty() {
+                break;
+            }
+            let category = rng.pick(&splittable);
+            let (start, end) = ranges[category];
+            let value = rng.range(start + 1, end - 2);
+            let (op, applies, remains) = match rng.chance(0.5) {
+                true => ('<', (start, value), (value, end)),
+                false => ('>', (value + 1, end), (start, value + 1)),
+            };
+            let mut to = ranges;
+            to[category] = applies;
+            ranges[category] = remains;
+            let to = target(rng, to, idx == 0);
+            rules.push(format!("{}{}{}:{}", CATEGORIES[category], op, value, to));
+        }
+        rules.push(target(rng, ranges, rules.is_empty()));
+        workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+    }
+    rng.shuffle(&mut workflows);
+
+    let mut out = workflows;
+    out.push(String::new());
+    for _ in 0..size / 3 + 1 {
+        let ratings: Vec<String> = CATEGORIES
+            .iter()
+            .map(|c| format!("{}={}", c, rng.range(1, 4000)))
+            .collect();
+        out.push(format!("{{{}}}", ratings.join(",")));
+    }
+    out
+}
+
+// Lower case names of two or three letters, never `in`.
+fn workflow_name(idx: usize) -> String {
+    let letter = |n: usize| char::from(b'a' + (n % 26) as u8);
+    let name: String = match idx < 26 * 26 {
+        true => [letter(idx / 26), letter(idx)].iter().collect(),
+        false => [letter(idx / 676), letter(idx / 26), letter(idx)]
+            .iter()
+            .collect(),
+    };
+    match name.as_str() {
+        "in" => String::from("inn"),
+        _ => name,
+    }
+}
+
+fn day20(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Four counters like the official input: a chain of flip-flops counting button presses, and a
+    // conjunction that fires and resets the chain when the count reaches the counter's period.
+    // All four firing at once sends a low pulse to rx.
+    let bits = size;
+    let mut names: Vec<String> = (0..26 * 26)
+        .map(|n| {
+            let letter = |n: usize| char::from(b'a' + (n % 26) as u8);
+            [letter(n / 26), letter(n)].iter().collect()
+        })
+        .filter(|n| n != "rx")
+        .collect();
+    rng.shuffle(&mut names);
+    let mut names = names.into_iter();
+    let mut next = || names.next().expect("fewer than 600 modules");
+
+    let output = next();
+    let mut modules = vec![format!("&{} -> rx", output)];
+    let mut starts = Vec::new();
+    for _ in 0..4 {
+        let period = rng.range((1 << (bits - 1)) + 1, (1 << bits) - 1) | 1;
+        let flip_flops: Vec<String> = (0..bits).map(|_| next()).collect();
+        let (counter, inverter) = (next(), next());
+
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut targets = Vec::new();
+            if let Some(following) = flip_flops.get(bit + 1) {
+                targets.push(following.clone());
+            }
+            if period >> bit & 1 == 1 {
+                targets.push(counter.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            modules.push(format!("%{} -> {}", flip_flop, targets.join(", ")));
+        }
+        resets.push(inverter.clone());
+        modules.push(format!("&{} -> {}", counter, resets.join(", ")));
+        modules.push(format!("&{} -> {}", inverter, output));
+        starts.push(flip_flops[0].clone());
+    }
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut modules);
+    modules
+}
+
+fn day21(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Like the official garden: odd sized, starting in the middle, with the middle row and column
+    // and the border free of rocks.
+    let size = size | 1;
+    let middle = size / 2;
+    let mut grid = rng.grid(size, size, &[('.', 0.88), ('#', 0.12)]);
+    for idx in 0..size {
+        for (x, y) in [
+            (idx, middle),
+            (middle, idx),
+            (idx, 0),
+            (idx, size - 1),
+            (0, idx),
+            (size - 1, idx),
+        ] {
+            grid[y][x] = '.';
+        }
+    }
+    grid[middle][middle] = 'S';
+    lines(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::Part;
+
+    fn solve(day: u8, input: &str) -> Vec<String> {
+        let day = days::find(day).unwrap();
+        let lines = input.lines().map(String::from).collect();
+        let parsed = day
+            .parse(lines)
+            .unwrap_or_else(|e| panic!("day {}: {}", day.number, e));
+        Part::ALL
+            .iter()
+            .map(|part| day.solve(&parsed, *part))
+            .collect()
+    }
+
+    #[test]
+    fn every_generated_input_is_solved() {
+        for generator in GENERATORS {
+            // Small inputs, to keep the slower days quick in a debug build.
+            let size = generator.sizes.0.max(generator.default_size / 20);
+            for seed in 1..=3 {
+                let input = generator.generate(seed, size);
+                assert_eq!(input, generator.generate(seed, size));
+                let answers = solve(generator.day, &input);
+                assert!(
+                    answers.iter().all(|a| !a.is_empty()),
+                    "day {} seed {}: {:?}",
+                    generator.day,
+                    seed,
+                    answers
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn day08_answers_depend_on_the_seed() {
+        let generator = generator(8).unwrap();
+        let answers: Vec<Vec<String>> = (1..=3)
+            .map(|seed| solve(8, &generator.generate(seed, generator.default_size)))
+            .collect();
+        assert_ne!(answers[0], answers[1]);
+        assert_ne!(answers[1], answers[2]);
+    }
+}
@@ -0,0 +1,256 @@
+use std::fmt::{Display, Formatter};
+
+/// One of the four cardinal directions. North is up, towards lower `y`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, starting north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Ordinal {
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
+}
+
+impl Ordinal {
+    /// Clockwise, starting north-east.
+    pub const ALL: [Ordinal; 4] = [
+        Ordinal::NorthEast,
+        Ordinal::SouthEast,
+        Ordinal::SouthWest,
+        Ordinal::NorthWest,
+    ];
+
+    pub fn opposite(self) -> Ordinal {
+        self.turn_right().turn_right()
+    }
+
+    pub fn turn_right(self) -> Ordinal {
+        match self {
+            Ordinal::NorthEast => Ordinal::SouthEast,
+            Ordinal::SouthEast => Ordinal::SouthWest,
+            Ordinal::SouthWest => Ordinal::NorthWest,
+            Ordinal::NorthWest => Ordinal::NorthEast,
+        }
+    }
+
+    pub fn turn_left(self) -> Ordinal {
+        self.turn_right().opposite()
+    }
+
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Ordinal::NorthEast => (1, -1),
+            Ordinal::SouthEast => (1, 1),
+            Ordinal::SouthWest => (-1, 1),
+            Ordinal::NorthWest => (-1, -1),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn step(self, dir: Direction) -> Point {
+        self.step_by(dir, 1)
+    }
+
+    pub fn step_by(self, dir: Direction, steps: i64) -> Point {
+        let (dx, dy) = dir.offset();
+        Point::new(self.x + dx * steps, self.y + dy * steps)
+    }
+
+    pub fn step_diagonal(self, dir: Ordinal) -> Point {
+        let (dx, dy) = dir.offset();
+        Point::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// A point that cannot go below zero, e.g. a position in a [`Grid`](crate::Grid).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub struct UPoint {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl UPoint {
+    pub fn new(x: usize, y: usize) -> UPoint {
+        UPoint { x, y }
+    }
+
+    /// The next point in `dir`, or `None` when that would go below zero.
+    pub fn step(self, dir: Direction) -> Option<UPoint> {
+        self.offset(dir.offset())
+    }
+
+    pub fn step_diagonal(self, dir: Ordinal) -> Option<UPoint> {
+        self.offset(dir.offset())
+    }
+
+    pub fn manhattan(self, other: UPoint) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    fn offset(self, (dx, dy): (i64, i64)) -> Option<UPoint> {
+        Some(UPoint::new(
+            self.x.checked_add_signed(dx as isize)?,
+            self.y.checked_add_signed(dy as isize)?,
+        ))
+    }
+}
+
+impl From<(usize, usize)> for UPoint {
+    fn from((x, y): (usize, usize)) -> Self {
+        UPoint::new(x, y)
+    }
+}
+
+impl From<UPoint> for Point {
+    fn from(value: UPoint) -> Self {
+        Point::new(value.x as i64, value.y as i64)
+    }
+}
+
+impl TryFrom<Point> for UPoint {
+    type Error = Point;
+
+    fn try_from(value: Point) -> Result<Self, Point> {
+        match (usize::try_from(value.x), usize::try_from(value.y)) {
+            (Ok(x), Ok(y)) => Ok(UPoint::new(x, y)),
+            _ => Err(value),
+        }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for UPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_turn_and_reverse() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            let ((dx, dy), (ox, oy)) = (dir.offset(), dir.opposite().offset());
+            assert_eq!((dx + ox, dy + oy), (0, 0));
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::North.offset(), (0, -1));
+    }
+
+    #[test]
+    fn ordinals_turn_and_reverse() {
+        for dir in Ordinal::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+        }
+        assert_eq!(Ordinal::NorthEast.opposite(), Ordinal::SouthWest);
+        assert_eq!(Ordinal::NorthWest.turn_right(), Ordinal::NorthEast);
+    }
+
+    #[test]
+    fn points_step_and_measure() {
+        let p = Point::new(-2, 3);
+        assert_eq!(p.step(Direction::North), Point::new(-2, 2));
+        assert_eq!(p.step_by(Direction::West, 4), Point::new(-6, 3));
+        assert_eq!(p.step_diagonal(Ordinal::SouthEast), Point::new(-1, 4));
+        assert_eq!(p.manhattan(Point::new(1, -1)), 7);
+    }
+
+    #[test]
+    fn upoints_stop_at_zero() {
+        let origin = UPoint::new(0, 0);
+        assert_eq!(origin.step(Direction::North), None);
+        assert_eq!(origin.step(Direction::West), None);
+        assert_eq!(origin.step(Direction::East), Some(UPoint::new(1, 0)));
+        assert_eq!(
+            origin.step_diagonal(Ordinal::SouthEast),
+            Some(UPoint::new(1, 1))
+        );
+        for dir in [Ordinal::NorthEast, Ordinal::SouthWest, Ordinal::NorthWest] {
+            assert_eq!(origin.step_diagonal(dir), None);
+        }
+        let edge = UPoint::new(usize::MAX, 5);
+        assert_eq!(edge.step(Direction::East), None);
+        assert_eq!(
+            edge.step(Direction::West),
+            Some(UPoint::new(usize::MAX - 1, 5))
+        );
+        assert_eq!(UPoint::new(2, 7).manhattan(UPoint::new(5, 3)), 7);
+    }
+
+    #[test]
+    fn converts_between_points() {
+        assert_eq!(UPoint::from((4, 2)), UPoint::new(4, 2));
+        assert_eq!(Point::from(UPoint::new(4, 2)), Point::new(4, 2));
+        assert_eq!(UPoint::try_from(Point::new(4, 2)), Ok(UPoint::new(4, 2)));
+        assert_eq!(UPoint::try_from(Point::new(4, -1)), Err(Point::new(4, -1)));
+        assert_eq!(UPoint::new(4, 2).to_string(), "(4, 2)");
+    }
+}
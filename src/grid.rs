@@ -0,0 +1,364 @@
+use crate::geometry::{Direction, Ordinal, UPoint};
+use crate::AocError;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, stored row by row. Positions are [`UPoint`]s, with `(0, 0)` top left.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row order; `cells` must hold exactly `width * height` items.
+    /// Fails when the grid would be empty.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Grid<T>, AocError> {
+        if width == 0 || height == 0 {
+            return Err(AocError::invalid("grid is empty"));
+        }
+        if cells.len() != width * height {
+            return Err(AocError::invalid(format!(
+                "{} cells do not fill a {}x{} grid",
+                cells.len(),
+                width,
+                height
+            )));
+        }
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// A grid with every cell set to `value`; panics when `width` or `height` is 0.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            width > 0 && height > 0,
+            "a {}x{} grid is empty",
+            width,
+            height
+        );
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line, converting every character with `parse_cell`.
+    /// Fails when the grid is empty or its rows differ in length.
+    pub fn parse<S, F>(
+        lines: impl IntoIterator<Item = S>,
+        parse_cell: F,
+    ) -> Result<Grid<T>, AocError>
+    where
+        S: AsRef<str>,
+        F: Fn(char) -> Result<T, AocError>,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in lines.into_iter().enumerate() {
+            let row_start = cells.len();
+            for c in line.as_ref().chars() {
+                cells.push(parse_cell(c).map_err(|e| e.on_line(y))?);
+            }
+            let row_len = cells.len() - row_start;
+            if y == 0 {
+                width = row_len;
+            } else if row_len != width {
+                return Err(AocError::parse("grid rows differ in length").on_line(y));
+            }
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(AocError::invalid("grid is empty"));
+        }
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: impl Into<UPoint>) -> bool {
+        let pos = pos.into();
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: impl Into<UPoint>) -> Option<&T> {
+        self.index_of(pos.into()).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<UPoint>) -> Option<&mut T> {
+        self.index_of(pos.into()).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn swap(&mut self, a: impl Into<UPoint>, b: impl Into<UPoint>) {
+        let (a, b) = (self.checked_index(a.into()), self.checked_index(b.into()));
+        self.cells.swap(a, b);
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = UPoint> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| UPoint::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (UPoint, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<UPoint> {
+        self.iter().find(|(_, t)| pred(t)).map(|(pos, _)| pos)
+    }
+
+    pub fn step(&self, pos: UPoint, dir: Direction) -> Option<UPoint> {
+        pos.step(dir).filter(|next| self.contains(*next))
+    }
+
+    pub fn neighbours4(&self, pos: impl Into<UPoint>) -> impl Iterator<Item = UPoint> + '_ {
+        let pos = pos.into();
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn neighbours8(&self, pos: impl Into<UPoint>) -> impl Iterator<Item = UPoint> + '_ {
+        let pos = pos.into();
+        let diagonals = Ordinal::ALL
+            .into_iter()
+            .filter_map(move |dir| pos.step_diagonal(dir))
+            .filter(|next| self.contains(*next));
+        self.neighbours4(pos).chain(diagonals)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of column `x`, top to bottom; panics when `x` is out of bounds, like [`Grid::row`].
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside a {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| UPoint::new(y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| {
+            UPoint::new(y, self.height - 1 - x)
+        })
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| {
+            UPoint::new(self.width - 1 - y, x)
+        })
+    }
+
+    // Builds a new grid where the cell at (x, y) is copied from `source(x, y)` in this one.
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> UPoint,
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    fn index_of(&self, pos: UPoint) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.y * self.width + pos.x)
+        } else {
+            None
+        }
+    }
+
+    fn checked_index(&self, pos: UPoint) -> usize {
+        match self.index_of(pos) {
+            Some(idx) => idx,
+            None => panic!("{} is outside a {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> Index<UPoint> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: UPoint) -> &T {
+        &self.cells[self.checked_index(pos)]
+    }
+}
+
+impl<T> IndexMut<UPoint> for Grid<T> {
+    fn index_mut(&mut self, pos: UPoint) -> &mut T {
+        let idx = self.checked_index(pos);
+        &mut self.cells[idx]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        &self[UPoint::from(pos)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        &mut self[UPoint::from(pos)]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(["abc", "def"], Ok).unwrap()
+    }
+
+    #[test]
+    fn new_checks_the_cell_count() {
+        let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert!(Grid::new(3, 2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn new_rejects_empty_grids() {
+        assert!(Grid::<u8>::new(0, 3, vec![]).is_err());
+        assert!(Grid::<u8>::new(3, 0, vec![]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "grid is empty")]
+    fn filled_rejects_zero_width() {
+        Grid::filled(0, 3, '.');
+    }
+
+    #[test]
+    fn parse_rejects_ragged_and_empty_input() {
+        assert!(Grid::parse(["ab", "c"], Ok).is_err());
+        assert!(Grid::<char>::parse(Vec::<&str>::new(), Ok).is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        let rows: Vec<String> = grid.rows().map(|r| r.iter().collect()).collect();
+        assert_eq!(rows, ["abc", "def"]);
+        let column: String = grid.column(2).collect();
+        assert_eq!(column, "cf");
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside a 3x2 grid")]
+    fn column_out_of_bounds() {
+        grid().column(3).for_each(drop);
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let grid = grid();
+        assert_eq!(grid.get((1, 1)), Some(&'e'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = grid();
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+    }
+}
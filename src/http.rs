@@ -0,0 +1,232 @@
+use crate::toml;
+use crate::AocError;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends a request and waits for the whole response.
+///
+/// Plain `http://` URLs are handled here; `https://` goes through `curl`, to keep TLS out of the crate.
+pub(crate) fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, AocError> {
+    let response = if let Some(rest) = url.strip_prefix("http://") {
+        plain(method, rest, headers, body)
+    } else if url.starts_with("https://") {
+        curl(method, url, headers, body)
+    } else {
+        return Err(AocError::Config(format!(
+            "unsupported URL {:?}, expected http:// or https://",
+            url
+        )));
+    };
+    response.map_err(|e| AocError::Server(format!("{} {}: {}", method, url, e)))
+}
+
+fn plain(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    let (authority, path) = match url.find('/') {
+        Some(idx) => url.split_at(idx),
+        None => (url, "/"),
+    };
+    let address = match authority.contains(':') {
+        true => String::from(authority),
+        false => format!("{}:80", authority),
+    };
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        method, path, authority
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    let body = body.unwrap_or("");
+    if !body.is_empty() || method == "POST" {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body);
+
+    let mut stream = TcpStream::connect(&address).map_err(|e| e.to_string())?;
+    stream
+        .set_read_timeout(Some(TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| e.to_string())?;
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw).map_err(|e| e.to_string())?;
+
+    parse_response(&String::from_utf8_lossy(&raw))
+}
+
+fn parse_response(raw: &str) -> Result<Response, String> {
+    let (head, body) = raw
+        .split_once("\r\n\r\n")
+        .ok_or("response ends before its headers do")?;
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|s| s.parse().ok())
+        .ok_or("response has no status line")?;
+    let chunked = lines.any(|l| {
+        l.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+
+    let body = match chunked {
+        true => unchunk(body)?,
+        false => String::from(body),
+    };
+    Ok(Response { status, body })
+}
+
+fn unchunk(mut body: &str) -> Result<String, String> {
+    let mut out = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n").ok_or("truncated chunk")?;
+        let size = usize::from_str_radix(size.split(';').next().unwrap().trim(), 16)
+            .map_err(|_| format!("invalid chunk size {:?}", size))?;
+        if size == 0 {
+            return Ok(out);
+        }
+        let chunk = rest.get(..size).ok_or("truncated chunk")?;
+        out.push_str(chunk);
+        body = rest[size..].strip_prefix("\r\n").unwrap_or(&rest[size..]);
+    }
+}
+
+// The headers and body go to curl on stdin, so the session token never shows up in `ps`. curl's
+// config file quotes strings the way TOML does.
+fn curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, String> {
+    let mut config = String::new();
+    for (name, value) in headers {
+        config.push_str(&format!(
+            "header = {}\n",
+            toml::quote(&format!("{}: {}", name, value))
+        ));
+    }
+    if let Some(body) = body {
+        config.push_str(&format!("data-binary = {}\n", toml::quote(body)));
+    }
+
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--config", "-"])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--request", method, "--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("cannot run curl: {}", e))?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(config.as_bytes())
+        .map_err(|e| e.to_string())?;
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').ok_or("curl printed no status")?;
+    let status = status
+        .parse()
+        .map_err(|_| format!("curl printed an invalid status {:?}", status))?;
+    Ok(Response {
+        status,
+        body: String::from(body),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::StubServer;
+
+    #[test]
+    fn reads_a_plain_response() {
+        let response = parse_response("HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello").unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "hello");
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn reads_a_chunked_response() {
+        let raw = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n7;ext\r\n, world\r\n0\r\n\r\n";
+        assert_eq!(parse_response(raw).unwrap().body, "hello, world");
+    }
+
+    #[test]
+    fn rejects_truncated_responses() {
+        assert!(parse_response("HTTP/1.1 200 OK\r\nContent-Length: 5").is_err());
+        assert!(parse_response("garbage\r\n\r\n").is_err());
+        let raw = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nff\r\nshort";
+        assert!(parse_response(raw).is_err());
+    }
+
+    #[test]
+    fn posts_to_a_local_server() {
+        let server = StubServer::start(vec![(302, "moved")]);
+        let url = format!("{}/2023/day/1/answer", server.url());
+        let response = request(
+            "POST",
+            &url,
+            &[("Cookie", "session=abc")],
+            Some("level=1&answer=42"),
+        )
+        .unwrap();
+        assert_eq!(response.status, 302);
+        assert!(!response.is_success());
+
+        let sent = &server.requests()[0];
+        assert!(
+            sent.starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"),
+            "{}",
+            sent
+        );
+        assert!(sent.contains("Cookie: session=abc\r\n"));
+        assert!(sent.contains("Content-Length: 17\r\n"));
+        assert!(sent.ends_with("\r\n\r\nlevel=1&answer=42"));
+    }
+
+    #[test]
+    fn rejects_other_schemes() {
+        let err = request("GET", "ftp://example.com", &[], None).unwrap_err();
+        assert!(matches!(err, AocError::Config(_)), "{:?}", err);
+    }
+}
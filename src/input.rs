@@ -0,0 +1,111 @@
+use crate::cache::{fnv1a, FNV_OFFSET};
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the puzzle inputs, overriding `./inputs`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// The default input for a day: `dayNN` in `$AOC_INPUT_DIR`, or in `./inputs` when that is unset.
+    pub fn for_day(day: u8) -> InputSource {
+        InputSource::File(day_path(day))
+    }
+
+    /// A named example input next to the day's input: `dayNN_<name>`, e.g. `day21_test`.
+    pub fn example(day: u8, name: &str) -> InputSource {
+        InputSource::File(example_path(day, name))
+    }
+
+    /// A short name for the input in reports: the file name, or `stdin`.
+    pub fn id(&self) -> String {
+        match self {
+            InputSource::File(path) => path.file_name().map_or_else(
+                || path.display().to_string(),
+                |n| n.to_string_lossy().into(),
+            ),
+            InputSource::Stdin => String::from("stdin"),
+        }
+    }
+
+    /// The name of the input together with a hash of where it is, e.g. `day05-1c0ffee0a1b2c3d4`, to
+    /// keep what is saved about inputs of the same name in different directories apart.
+    pub fn unique_id(&self) -> String {
+        let location = match self {
+            InputSource::File(path) => fs::canonicalize(path).unwrap_or_else(|_| path.clone()),
+            InputSource::Stdin => PathBuf::new(),
+        };
+        let hash = fnv1a(FNV_OFFSET, location.as_os_str().as_encoded_bytes());
+        format!("{}-{:016x}", self.id(), hash)
+    }
+
+    pub fn exists(&self) -> bool {
+        match self {
+            InputSource::File(path) => path.is_file(),
+            InputSource::Stdin => true,
+        }
+    }
+}
+
+/// Name of the example input picked by `--example` when no name is given.
+pub const DEFAULT_EXAMPLE: &str = "test";
+
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("./inputs"))
+}
+
+pub fn day_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day{:02}", day))
+}
+
+pub fn example_path(day: u8, name: &str) -> PathBuf {
+    input_dir().join(format!("day{:02}_{}", day, name))
+}
+
+/// `-` means stdin, anything else is a file path.
+impl From<&str> for InputSource {
+    fn from(value: &str) -> Self {
+        if value == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(value))
+        }
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(value: &Path) -> Self {
+        InputSource::File(value.to_path_buf())
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(value: PathBuf) -> Self {
+        InputSource::File(value)
+    }
+}
+
+impl From<&InputSource> for InputSource {
+    fn from(value: &InputSource) -> Self {
+        value.clone()
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
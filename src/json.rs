@@ -0,0 +1,290 @@
+use crate::AocError;
+
+/// A parsed JSON value. Objects keep their keys in the order they were written.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(text: &str) -> Result<Json, AocError> {
+        let mut parser = Parser {
+            chars: text.chars().collect(),
+            pos: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(value),
+            Some(c) => Err(parser.error(format!("unexpected {:?} after the value", c))),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn entries(&self) -> &[(String, Json)] {
+        match self {
+            Json::Object(entries) => entries,
+            _ => &[],
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Json::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as u64),
+            // Some fields are numbers in one leaderboard and strings in another.
+            Json::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn error(&self, msg: String) -> AocError {
+        AocError::parse(format!("{} at character {}", msg, self.pos))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), AocError> {
+        self.skip_whitespace();
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(format!("expected {:?}, found {:?}", expected, c))),
+            None => Err(self.error(format!("expected {:?}, found the end", expected))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, AocError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => Ok(Json::String(self.string()?)),
+            Some('t') => self.literal("true", Json::Bool(true)),
+            Some('f') => self.literal("false", Json::Bool(false)),
+            Some('n') => self.literal("null", Json::Null),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) => Err(self.error(format!("unexpected {:?}", c))),
+            None => Err(self.error(String::from("unexpected end"))),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, AocError> {
+        for expected in word.chars() {
+            if self.next() != Some(expected) {
+                return Err(self.error(format!("expected {}", word)));
+            }
+        }
+        Ok(value)
+    }
+
+    fn number(&mut self) -> Result<Json, AocError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+        {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse()
+            .map(Json::Number)
+            .map_err(|_| self.error(format!("invalid number {:?}", text)))
+    }
+
+    fn string(&mut self) -> Result<String, AocError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(out),
+                Some('\\') => match self.next() {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('r') => out.push('\r'),
+                    Some('b') => out.push('\u{8}'),
+                    Some('f') => out.push('\u{c}'),
+                    Some('u') => out.push(self.unicode_escape()?),
+                    Some(c) => out.push(c),
+                    None => return Err(self.error(String::from("unterminated string"))),
+                },
+                Some(c) => out.push(c),
+                None => return Err(self.error(String::from("unterminated string"))),
+            }
+        }
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, AocError> {
+        let mut code = self.hex4()?;
+        // Characters outside the basic plane come as a surrogate pair.
+        if (0xD800..0xDC00).contains(&code) && self.chars[self.pos..].starts_with(&['\\', 'u']) {
+            self.pos += 2;
+            let low = self.hex4()?;
+            code = 0x10000 + ((code - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF);
+        }
+        Ok(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn hex4(&mut self) -> Result<u32, AocError> {
+        let digits: String = self.chars.iter().skip(self.pos).take(4).collect();
+        self.pos += 4;
+        u32::from_str_radix(&digits, 16)
+            .map_err(|_| self.error(format!("invalid escape \\u{}", digits)))
+    }
+
+    fn array(&mut self) -> Result<Json, AocError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Json::Array(items)),
+                _ => return Err(self.error(String::from("expected , or ] in an array"))),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, AocError> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Json::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Json::Object(entries)),
+                _ => return Err(self.error(String::from("expected , or } in an object"))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_objects_in_order() {
+        let json = Json::parse(
+            r#" {"event": "2023", "members": {"12": {"name": "Ada", "stars": 4,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1701406800}}}},
+                "7": {"name": null, "stars": 0, "completion_day_level": {}}},
+                "flags": [true, false, []]} "#,
+        )
+        .unwrap();
+        let members = json.get("members").unwrap();
+        let ids: Vec<&str> = members.entries().iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(ids, ["12", "7"]);
+        let ada = members.get("12").unwrap();
+        assert_eq!(ada.get("name").and_then(Json::as_str), Some("Ada"));
+        let star = ada
+            .get("completion_day_level")
+            .and_then(|d| d.get("1"))
+            .and_then(|d| d.get("1"))
+            .and_then(|d| d.get("get_star_ts"));
+        assert_eq!(star.and_then(Json::as_u64), Some(1701406800));
+        assert_eq!(members.get("7").unwrap().get("name"), Some(&Json::Null));
+        assert_eq!(
+            json.get("flags"),
+            Some(&Json::Array(vec![
+                Json::Bool(true),
+                Json::Bool(false),
+                Json::Array(vec![])
+            ]))
+        );
+        assert_eq!(json.get("event").and_then(Json::as_u64), Some(2023));
+    }
+
+    #[test]
+    fn reads_escapes() {
+        let json = Json::parse(r#""q\" b\\ s\/ n\n t\t é 🎄""#).unwrap();
+        assert_eq!(json, Json::String(String::from("q\" b\\ s/ n\n t\t é 🎄")));
+    }
+
+    #[test]
+    fn reads_numbers() {
+        for (text, value) in [
+            ("0", 0.0),
+            ("-12", -12.0),
+            ("3.25", 3.25),
+            ("1e3", 1000.0),
+            ("-2.5E-1", -0.25),
+        ] {
+            assert_eq!(Json::parse(text).unwrap(), Json::Number(value), "{}", text);
+        }
+        assert_eq!(Json::parse("-1").unwrap().as_u64(), None);
+        assert_eq!(Json::parse("1.5").unwrap().as_u64(), None);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for text in [
+            "",
+            "{",
+            "[1, 2",
+            "[1 2]",
+            r#"{"a" 1}"#,
+            r#"{"a": 1,}"#,
+            r#"{a: 1}"#,
+            r#""unterminated"#,
+            r#""bad \u12 escape""#,
+            "tru",
+            "1 2",
+            "--1",
+        ] {
+            assert!(Json::parse(text).is_err(), "{:?} parsed", text);
+        }
+    }
+}
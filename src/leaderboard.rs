@@ -0,0 +1,246 @@
+use crate::json::Json;
+use crate::report::aligned;
+use crate::{AocError, Part, Record};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// When the member got each part's star, as Unix time, by day.
+    pub stars_at: BTreeMap<u8, [Option<u64>; 2]>,
+}
+
+/// The longest run of consecutive days a member got both stars on, and the run up to the latest day.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Streaks {
+    pub longest: u32,
+    pub current: u32,
+}
+
+impl Leaderboard {
+    pub fn parse(text: &str) -> Result<Leaderboard, AocError> {
+        let json = Json::parse(text)?;
+        let year = json
+            .get("event")
+            .and_then(Json::as_u64)
+            .ok_or_else(|| AocError::parse("leaderboard has no event year"))?;
+        let members = json
+            .get("members")
+            .ok_or_else(|| AocError::parse("leaderboard has no members"))?
+            .entries()
+            .iter()
+            .map(|(id, member)| {
+                Member::from_json(member).map_err(|e| match e {
+                    AocError::Parse { msg, .. } => {
+                        AocError::parse(format!("member {}: {}", id, msg))
+                    }
+                    other => other,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Leaderboard {
+            year: year as u16,
+            members,
+        })
+    }
+
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut ranking: Vec<&Member> = self.members.iter().collect();
+        ranking.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.name.cmp(&b.name))
+        });
+        ranking
+    }
+
+    /// When `day`'s puzzle unlocked: midnight in UTC-5, on that day of December.
+    pub fn unlocked_at(&self, day: u8) -> u64 {
+        let days = days_from_civil(self.year as i64, 12, day as i64);
+        (days * 86_400 + 5 * 3600) as u64
+    }
+
+    pub fn star_time(&self, member: &Member, day: u8, part: Part) -> Option<Duration> {
+        let at = member.star_at(day, part)?;
+        Some(Duration::from_secs(
+            at.saturating_sub(self.unlocked_at(day)),
+        ))
+    }
+
+    pub fn last_day(&self) -> u8 {
+        self.members
+            .iter()
+            .filter_map(|m| m.stars_at.keys().next_back())
+            .max()
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn streaks(&self, member: &Member) -> Streaks {
+        let mut streaks = Streaks::default();
+        for day in 1..=self.last_day() {
+            if member.star_at(day, Part::Two).is_some() {
+                streaks.current += 1;
+                streaks.longest = streaks.longest.max(streaks.current);
+            } else {
+                streaks.current = 0;
+            }
+        }
+        streaks
+    }
+
+    /// Rankings, then every day's star times with the time between the parts. `ours` are records
+    /// from running our own solutions, shown with the days they solve.
+    pub fn render(&self, ours: &[Record]) -> String {
+        let mut out = format!(
+            "Leaderboard {}, {} members\n",
+            self.year,
+            self.members.len()
+        );
+        let rows: Vec<Vec<String>> = self
+            .ranking()
+            .iter()
+            .enumerate()
+            .map(|(rank, m)| {
+                let streaks = self.streaks(m);
+                vec![
+                    (rank + 1).to_string(),
+                    m.name.clone(),
+                    m.local_score.to_string(),
+                    m.stars.to_string(),
+                    streaks.longest.to_string(),
+                    streaks.current.to_string(),
+                ]
+            })
+            .collect();
+        let header = [
+            "Rank",
+            "Member",
+            "Score",
+            "Stars",
+            "Longest streak",
+            "Current streak",
+        ];
+        out.push('\n');
+        out.push_str(&aligned(&header.map(String::from), &rows, &[0, 2, 3, 4, 5]));
+
+        for day in 1..=self.last_day() {
+            let _ = writeln!(out, "\nDay {:02}{}", day, our_times(ours, day));
+            let mut rows: Vec<(Option<Duration>, Option<Duration>, Vec<String>)> = self
+                .members
+                .iter()
+                .filter(|m| m.stars_at.contains_key(&day))
+                .map(|m| {
+                    let first = self.star_time(m, day, Part::One);
+                    let second = self.star_time(m, day, Part::Two);
+                    let delta = first.zip(second).map(|(a, b)| b.saturating_sub(a));
+                    let row = vec![m.name.clone(), clock(first), clock(second), clock(delta)];
+                    (second, first, row)
+                })
+                .collect();
+            // Both stars first, fastest first; then those with only the first star.
+            rows.sort_by_key(|(second, first, _)| (second.is_none(), *second, *first));
+            let rows: Vec<Vec<String>> = rows.into_iter().map(|(_, _, row)| row).collect();
+            let header = ["Member", "Part 1", "Part 2", "Delta"].map(String::from);
+            out.push_str(&aligned(&header, &rows, &[1, 2, 3]));
+        }
+        out
+    }
+}
+
+impl Member {
+    fn from_json(json: &Json) -> Result<Member, AocError> {
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(Json::as_u64)
+                .ok_or_else(|| AocError::parse(format!("no {}", key)))
+        };
+        let id = number("id")?;
+        let name = match json.get("name").and_then(Json::as_str) {
+            Some(name) => String::from(name),
+            None => format!("(anonymous user #{})", id),
+        };
+
+        let mut stars_at = BTreeMap::new();
+        let days = json
+            .get("completion_day_level")
+            .map_or(&[][..], Json::entries);
+        for (day, parts) in days {
+            let day: u8 = day
+                .parse()
+                .map_err(|_| AocError::parse(format!("invalid day {:?}", day)))?;
+            let star = |part: &str| {
+                parts
+                    .get(part)
+                    .and_then(|p| p.get("get_star_ts"))
+                    .and_then(Json::as_u64)
+            };
+            stars_at.insert(day, [star("1"), star("2")]);
+        }
+
+        Ok(Member {
+            id,
+            name,
+            local_score: number("local_score")?,
+            stars: number("stars")?,
+            stars_at,
+        })
+    }
+
+    pub fn star_at(&self, day: u8, part: Part) -> Option<u64> {
+        let stars = self.stars_at.get(&day)?;
+        match part {
+            Part::One => stars[0],
+            Part::Two => stars[1],
+        }
+    }
+}
+
+// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// `1:02:03`, or `-` without a time. Hours keep counting past a day.
+fn clock(time: Option<Duration>) -> String {
+    match time {
+        Some(time) => {
+            let secs = time.as_secs();
+            format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+        }
+        None => String::from("-"),
+    }
+}
+
+fn our_times(ours: &[Record], day: u8) -> String {
+    let times: Vec<String> = ours
+        .iter()
+        .filter(|r| r.day == day)
+        .map(|r| match r.solve_time {
+            Some(time) if r.status.is_ok() => format!("{} {:.1?}", r.part, time),
+            _ => format!("{} {}", r.part, r.status),
+        })
+        .collect();
+    match times.is_empty() {
+        true => String::new(),
+        false => format!(", our solution: {}", times.join(", ").to_lowercase()),
+    }
+}
@@ -1,13 +1,62 @@
+mod answers;
+mod baseline;
+mod bench;
+mod cache;
+mod config;
+pub mod days;
+mod error;
+mod examples;
+mod fetch;
+mod generate;
+pub mod geometry;
+mod grid;
+mod html;
+mod http;
+mod input;
+mod json;
+mod leaderboard;
+mod memory;
+pub mod progress;
+mod report;
+pub mod runner;
+mod scaffold;
+pub mod span;
+mod submit;
+#[cfg(test)]
+mod test_support;
+mod toml;
+
+pub use answers::Answers;
+pub use baseline::{Baseline, Change};
+pub use bench::{bench, bench_with_setup, build_profile, Stats};
+pub use cache::{cache_dir, ResultCache, CACHE_DIR_VAR};
+pub use config::{Config, CONFIG_VAR, SESSION_VAR};
+pub use error::AocError;
+pub use examples::{extract_examples, save_example, Example, Saved};
+pub use fetch::{fetch_input, fetch_leaderboard, Fetched};
+pub use generate::{generator, Generator, GENERATORS};
+pub use grid::Grid;
+pub use input::{day_path, example_path, input_dir, InputSource, DEFAULT_EXAMPLE, INPUT_DIR_VAR};
+pub use leaderboard::{Leaderboard, Member, Streaks};
+pub use memory::{human_bytes, AllocStats, CountingAllocator};
+pub use report::{compare_runs, timing_tree, Format, Record, Status};
+pub use scaffold::{new_day, solution_path};
+pub use submit::{submit_answer, Submission, Verdict};
+
 use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::io;
 use std::io::BufRead;
-use std::path::Path;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 pub struct TimedResult<T> {
-    res: T,
-    time: Duration,
+    pub res: T,
+    pub time: Duration,
+    /// Only counted when built with the `count-allocs` feature.
+    pub memory: Option<AllocStats>,
+    /// The spans the closure entered, see [`span::enter`].
+    pub spans: Vec<span::SpanTree>,
 }
 
 impl<T: Display> Display for TimedResult<T> {
@@ -17,24 +66,137 @@ impl<T: Display> Display for TimedResult<T> {
             "Result: {}, time: {} micros",
             self.res,
             self.time.as_micros()
-        )
+        )?;
+        if let Some(memory) = self.memory {
+            write!(f, ", {}", memory)?;
+        }
+        Ok(())
     }
 }
 
-pub fn run_timed<T: Display, F: Fn() -> T>(func: F) -> TimedResult<T> {
+/// A day's puzzle: parsing the input once, then solving each part from the parsed input.
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    fn part2(input: &Self::Input) -> impl Display;
+
+    /// Other implementations of the parts, raced against `part1` and `part2` by
+    /// `aoc bench --compare`. Those two stay the reference the others must agree with.
+    const VARIANTS: &'static [Variant<Self::Input>] = &[];
+}
+
+/// A named alternative implementation of one part of a [`Solution`].
+pub struct Variant<I: 'static> {
+    pub name: &'static str,
+    pub part: Part,
+    /// Answers [`Unsolved`] for inputs it cannot handle, which `--compare` then leaves out.
+    pub solve: fn(&I) -> String,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Part, AocError> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(AocError::parse(format!(
+                "invalid part {:?}, expected 1 or 2",
+                s
+            ))),
+        }
+    }
+}
+
+/// Placeholder answer for a part that has not been solved yet.
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+pub fn run_timed<T, F: FnOnce() -> T>(func: F) -> TimedResult<T> {
     let now = Instant::now();
-    let res = func();
+    // Recording spans allocates, which is left out of what the work allocated.
+    let ((res, memory), spans) = span::collect(|| memory::measure(func));
     let time = now.elapsed();
 
-    TimedResult { res, time }
+    TimedResult {
+        res,
+        time,
+        memory,
+        spans,
+    }
 }
 
-pub fn read_lines<P>(filename: P) -> impl Iterator<Item = String>
+/// Reads the whole input up front, so read errors surface before any parsing starts.
+/// Accepts an `InputSource`, a path, or `"-"` for stdin.
+pub fn read_lines<S>(source: S) -> Result<impl Iterator<Item = String>, AocError>
 where
-    P: AsRef<Path>,
+    S: Into<InputSource>,
 {
-    File::open(filename)
-        .map(|f| io::BufReader::new(f).lines())
-        .expect("Error opening input file")
-        .map(|l| l.expect("Error reading line from input file"))
+    let lines = match source.into() {
+        InputSource::File(path) => io::BufReader::new(File::open(path)?)
+            .lines()
+            .collect::<Result<Vec<String>, io::Error>>()?,
+        InputSource::Stdin => io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<Vec<String>, io::Error>>()?,
+    };
+
+    Ok(lines.into_iter())
+}
+
+/// Parses every line with `parse_line`, tagging errors with the line they occurred on.
+pub fn parse_lines<T, F>(
+    lines: impl Iterator<Item = String>,
+    parse_line: F,
+) -> Result<Vec<T>, AocError>
+where
+    F: Fn(&str) -> Result<T, AocError>,
+{
+    lines
+        .enumerate()
+        .map(|(idx, l)| parse_line(&l).map_err(|e| e.on_line(idx)))
+        .collect()
+}
+
+/// Like `str::parse`, but the error names the offending text.
+pub fn parse_num<T: FromStr>(s: &str) -> Result<T, AocError> {
+    s.trim()
+        .parse()
+        .map_err(|_| AocError::parse(format!("invalid number {:?}", s)))
 }
@@ -0,0 +1,147 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+
+/// The system allocator, counting every allocation it hands out.
+///
+/// Installed as the global allocator when the crate is built with the `count-allocs` feature.
+/// The counters are process wide, so the numbers are only meaningful while one piece of work
+/// runs at a time, i.e. not with `aoc run --jobs`.
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+// Signed, because memory allocated before a measurement may be freed during it.
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+thread_local! {
+    // Set while the measuring code itself allocates, e.g. to record spans.
+    static PAUSED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn paused() -> bool {
+    PAUSED.try_with(Cell::get).unwrap_or(false)
+}
+
+/// Runs `func` without counting what it allocates or frees on this thread: for the bookkeeping
+/// done around the measured work, like recording its spans.
+pub(crate) fn uncounted<T>(func: impl FnOnce() -> T) -> T {
+    let was_paused = PAUSED.replace(true);
+    let res = func();
+    PAUSED.set(was_paused);
+    res
+}
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    grow(size as i64);
+}
+
+fn grow(delta: i64) {
+    let live = LIVE.fetch_add(delta, Ordering::Relaxed) + delta;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && !paused() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && !paused() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if !paused() {
+            grow(-(layout.size() as i64));
+        }
+    }
+
+    /// A reallocation counts as one allocation of the new size.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && !paused() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+            grow(new_size as i64 - layout.size() as i64);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+pub fn counting() -> bool {
+    cfg!(feature = "count-allocs")
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most memory the work held at once, on top of what was live when it started.
+    pub peak: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            human_bytes(self.bytes),
+            human_bytes(self.peak)
+        )
+    }
+}
+
+/// Runs `func` and counts its allocations, including those of threads it spawns.
+/// `None` when the build does not count allocations.
+pub fn measure<T, F: FnOnce() -> T>(func: F) -> (T, Option<AllocStats>) {
+    if !counting() {
+        return (func(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let res = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: (PEAK.load(Ordering::Relaxed) - live).max(0) as u64,
+    };
+    (res, Some(stats))
+}
+
+/// `1536` as `1.5 KiB`.
+pub fn human_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
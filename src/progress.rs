@@ -0,0 +1,168 @@
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Progress of a long-running loop, shared between the solution ticking it and the runner watching it.
+#[derive(Debug)]
+pub struct Progress {
+    label: String,
+    done: AtomicU64,
+    /// Zero while the total is unknown.
+    total: AtomicU64,
+    cancelled: AtomicBool,
+    loop_state: Mutex<(&'static str, Instant)>,
+}
+
+impl Progress {
+    pub fn new(label: &str) -> Progress {
+        Progress {
+            label: String::from(label),
+            done: AtomicU64::new(0),
+            total: AtomicU64::new(0),
+            cancelled: AtomicBool::new(false),
+            loop_state: Mutex::new(("steps", Instant::now())),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn started(&self) -> bool {
+        self.done.load(Ordering::Relaxed) > 0
+    }
+
+    fn restart(&self, unit: &'static str, total: Option<u64>) {
+        *self.loop_state.lock().unwrap() = (unit, Instant::now());
+        self.total.store(total.unwrap_or(0), Ordering::Relaxed);
+        self.done.store(0, Ordering::Relaxed);
+    }
+}
+
+/// A one-line summary: `Day 20 Part 2: 3200000 presses, 1.6M/s`, plus an ETA when the total is known.
+impl Display for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (unit, start) = *self.loop_state.lock().unwrap();
+        let done = self.done.load(Ordering::Relaxed);
+        let total = self.total.load(Ordering::Relaxed);
+        let rate = done as f64 / start.elapsed().as_secs_f64().max(1e-9);
+
+        write!(f, "{}: {}", self.label, done)?;
+        if total > 0 {
+            write!(f, "/{}", total)?;
+        }
+        write!(f, " {}, {}/s", unit, si(rate))?;
+        if total > done && rate > 0.0 {
+            let eta = Duration::from_secs_f64((total - done) as f64 / rate);
+            write!(f, ", ETA {:.0?}", eta)?;
+        }
+        Ok(())
+    }
+}
+
+fn si(value: f64) -> String {
+    match value {
+        v if v >= 1e9 => format!("{:.1}G", v / 1e9),
+        v if v >= 1e6 => format!("{:.1}M", v / 1e6),
+        v if v >= 1e3 => format!("{:.1}k", v / 1e3),
+        v => format!("{:.0}", v),
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Progress>>> = const { RefCell::new(None) };
+}
+
+/// Makes `progress` the one tickers on this thread report to.
+pub fn install(progress: Arc<Progress>) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(progress));
+}
+
+/// Reports a loop's progress to the runner, and tells the loop when the runner wants it to stop.
+///
+/// Outside the runner, e.g. in benchmarks, a ticker does nothing and is never cancelled.
+pub struct Ticker {
+    progress: Option<Arc<Progress>>,
+}
+
+impl Ticker {
+    /// Starts counting a loop of `total` steps, if known, each one named `unit` ("presses", "cycles").
+    pub fn start(unit: &'static str, total: Option<u64>) -> Ticker {
+        let progress = CURRENT.with(|current| current.borrow().clone());
+        if let Some(p) = &progress {
+            p.restart(unit, total);
+        }
+        Ticker { progress }
+    }
+
+    /// Counts one step. Returns `false` once the loop should stop.
+    pub fn tick(&self) -> bool {
+        self.advance(1)
+    }
+
+    /// Counts `steps` steps. Returns `false` once the loop should stop.
+    pub fn advance(&self, steps: u64) -> bool {
+        match &self.progress {
+            Some(p) => {
+                p.done.fetch_add(steps, Ordering::Relaxed);
+                !p.is_cancelled()
+            }
+            None => true,
+        }
+    }
+}
+
+/// The answer of a part whose loop stops when its [`Ticker`] says so. A cancelled part has no
+/// answer; the runner reports the timeout or Ctrl-C that stopped it instead.
+pub enum Cancellable<T> {
+    Done(T),
+    Cancelled,
+}
+
+impl<T: Display> Display for Cancellable<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cancellable::Done(answer) => answer.fmt(f),
+            Cancellable::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::Relaxed)
+}
+
+/// Turns the first Ctrl-C into a flag the runner can poll with [`interrupted`], so it can stop
+/// the running loop cleanly. A second Ctrl-C kills the process as usual.
+#[cfg(unix)]
+pub fn catch_interrupt() {
+    const SIGINT: i32 = 2;
+    const SIG_DFL: usize = 0;
+
+    extern "C" {
+        fn signal(signum: i32, handler: usize) -> usize;
+    }
+
+    extern "C" fn on_interrupt(_: i32) {
+        INTERRUPTED.store(true, Ordering::Relaxed);
+        // Only async-signal-safe calls in here: an atomic store and `signal`.
+        unsafe {
+            signal(SIGINT, SIG_DFL);
+        }
+    }
+
+    unsafe {
+        signal(SIGINT, on_interrupt as extern "C" fn(i32) as usize);
+    }
+}
+
+#[cfg(not(unix))]
+pub fn catch_interrupt() {}
@@ -0,0 +1,652 @@
+use crate::span::{self, SpanTree};
+use crate::{human_bytes, parse_num, AllocStats, AocError, Part, TimedResult};
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Parsing is shared by both parts of a day, so its records carry the same parse time.
+    /// `None` when parsing did not finish.
+    pub parse_time: Option<Duration>,
+    pub parse_spans: Vec<SpanTree>,
+    /// `None` when the part did not finish.
+    pub solve_time: Option<Duration>,
+    /// What solving allocated, when the build counts allocations and the part finished.
+    pub memory: Option<AllocStats>,
+    pub spans: Vec<SpanTree>,
+    /// Which input was solved, e.g. `day03` or `day21_test`.
+    pub input: String,
+    pub status: Status,
+    /// The answer came from the result cache; the times are those of the run that was cached.
+    pub cached: bool,
+}
+
+impl Record {
+    /// Records for parts that could not be solved, because the day failed before reaching them.
+    pub fn failed(day: u8, parts: &[Part], input: &str, status: Status) -> Vec<Record> {
+        parts
+            .iter()
+            .map(|part| Record {
+                day,
+                part: *part,
+                answer: String::new(),
+                parse_time: None,
+                parse_spans: Vec::new(),
+                solve_time: None,
+                memory: None,
+                spans: Vec::new(),
+                input: String::from(input),
+                status: status.clone(),
+                cached: false,
+            })
+            .collect()
+    }
+
+    /// Reads records back from [`Format::Csv`] output. Spans are not part of it, so they come back empty.
+    pub fn parse_csv(text: &str) -> Result<Vec<Record>, AocError> {
+        let mut rows = csv_rows(text).into_iter();
+        rows.next()
+            .ok_or_else(|| AocError::parse("no csv header"))?;
+        rows.enumerate()
+            .map(|(idx, row)| Record::from_csv_row(&row).map_err(|e| e.on_line(idx + 1)))
+            .collect()
+    }
+
+    fn from_csv_row(row: &[String]) -> Result<Record, AocError> {
+        let [day, part, answer, parse_ns, solve_ns, input, status, message, allocs, alloc_bytes, peak_bytes, cached] =
+            row
+        else {
+            return Err(AocError::parse(format!(
+                "expected 12 fields, found {}",
+                row.len()
+            )));
+        };
+        let optional = |field: &str| match field {
+            "" => Ok(None),
+            n => parse_num::<u64>(n).map(Some),
+        };
+        let nanos = |field: &str| Ok::<_, AocError>(optional(field)?.map(Duration::from_nanos));
+        let memory = match (
+            optional(allocs)?,
+            optional(alloc_bytes)?,
+            optional(peak_bytes)?,
+        ) {
+            (Some(allocations), Some(bytes), Some(peak)) => Some(AllocStats {
+                allocations,
+                bytes,
+                peak,
+            }),
+            _ => None,
+        };
+
+        Ok(Record {
+            day: parse_num(day)?,
+            part: part.parse()?,
+            answer: answer.clone(),
+            parse_time: nanos(parse_ns)?,
+            parse_spans: Vec::new(),
+            solve_time: nanos(solve_ns)?,
+            memory,
+            spans: Vec::new(),
+            input: input.clone(),
+            status: Status::parse(status, message)?,
+            cached: match cached.as_str() {
+                "true" => true,
+                "false" => false,
+                other => return Err(AocError::parse(format!("invalid cached {:?}", other))),
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Status {
+    Ok,
+    Error(String),
+    Panic(String),
+    Timeout(Duration),
+    Interrupted,
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        *self == Status::Ok
+    }
+
+    fn parse(status: &str, message: &str) -> Result<Status, AocError> {
+        match status {
+            "ok" => Ok(Status::Ok),
+            "error" => Ok(Status::Error(String::from(message))),
+            "panic" => Ok(Status::Panic(String::from(message))),
+            "timeout" => message
+                .strip_prefix("no answer after ")
+                .and_then(parse_duration)
+                .map(Status::Timeout)
+                .ok_or_else(|| AocError::parse(format!("invalid timeout {:?}", message))),
+            "interrupted" => Ok(Status::Interrupted),
+            other => Err(AocError::parse(format!("unknown status {:?}", other))),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            Status::Ok => String::new(),
+            Status::Error(msg) | Status::Panic(msg) => msg.clone(),
+            Status::Timeout(timeout) => format!("no answer after {:.1?}", timeout),
+            Status::Interrupted => String::from("stopped by Ctrl-C"),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Error(_) => write!(f, "error"),
+            Status::Panic(_) => write!(f, "panic"),
+            Status::Timeout(_) => write!(f, "timeout"),
+            Status::Interrupted => write!(f, "interrupted"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    /// The human readable lines `aoc run` has always printed.
+    Text,
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Format, AocError> {
+        match s {
+            "text" => Ok(Format::Text),
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(AocError::parse(format!(
+                "invalid format {:?}, expected text, table, json or csv",
+                s
+            ))),
+        }
+    }
+}
+
+impl Format {
+    pub fn render(self, records: &[Record]) -> String {
+        match self {
+            Format::Text => text(records),
+            Format::Table => table(records),
+            Format::Json => json(records),
+            Format::Csv => csv(records),
+        }
+    }
+}
+
+fn text(records: &[Record]) -> String {
+    let mut out = String::new();
+    let mut last_day = None;
+    for r in records {
+        if last_day != Some(r.day) {
+            let _ = writeln!(out, "Day {:02}", r.day);
+            if let Some(parse_time) = r.parse_time {
+                let _ = writeln!(out, "Parse: time: {} micros", parse_time.as_micros());
+            }
+            last_day = Some(r.day);
+        }
+        if let (Status::Ok, Some(time)) = (&r.status, r.solve_time) {
+            let result = TimedResult {
+                res: &r.answer,
+                time,
+                memory: r.memory,
+                spans: Vec::new(),
+            };
+            let cached = if r.cached { " (cached)" } else { "" };
+            let _ = writeln!(out, "{}: {}{}", r.part, result, cached);
+        } else {
+            let status = r.status.to_string().to_uppercase();
+            let _ = writeln!(out, "{}: {}: {}", r.part, status, r.status.message());
+        }
+    }
+    out
+}
+
+/// A tree per day of where its time went: parsing, each part, and the spans they entered, each
+/// with its share of the day's total.
+pub fn timing_tree(records: &[Record]) -> String {
+    let mut out = String::new();
+    let mut days: Vec<u8> = records.iter().map(|r| r.day).collect();
+    days.dedup();
+
+    for day in days {
+        let day_records: Vec<&Record> = records.iter().filter(|r| r.day == day).collect();
+        let mut phases = Vec::new();
+        if let Some(time) = day_records[0].parse_time {
+            phases.push(SpanTree {
+                name: "Parse",
+                time,
+                count: 1,
+                children: day_records[0].parse_spans.clone(),
+            });
+        }
+        for r in &day_records {
+            if let Some(time) = r.solve_time {
+                phases.push(SpanTree {
+                    name: match r.part {
+                        Part::One => "Part 1",
+                        Part::Two => "Part 2",
+                    },
+                    time,
+                    count: 1,
+                    children: r.spans.clone(),
+                });
+            }
+        }
+
+        let total = phases.iter().map(|p| p.time).sum();
+        let _ = writeln!(out, "Day {:02}: {:.1?}", day, total);
+        span::draw(&mut out, &phases, total, "");
+    }
+    out
+}
+
+/// The records of a run next to those of the run before it, matched by day and part.
+pub fn compare_runs(previous: &[Record], current: &[Record]) -> String {
+    let answer = |r: &Record| match r.status {
+        Status::Ok => r.answer.clone(),
+        ref failed => failed.to_string().to_uppercase(),
+    };
+    let time = |t: Option<Duration>| t.map_or(String::from("-"), |t| format!("{:.1?}", t));
+    let rows: Vec<Vec<String>> = current
+        .iter()
+        .map(|r| {
+            let before = previous.iter().find(|p| p.day == r.day && p.part == r.part);
+            let change = match (before, r.solve_time) {
+                (None, _) => String::from("first run"),
+                (Some(p), _) if answer(p) != answer(r) => String::from("answer changed"),
+                (Some(p), Some(now)) if r.status.is_ok() => match p.solve_time {
+                    Some(then) if !then.is_zero() => format!(
+                        "{:+.1}%",
+                        (now.as_secs_f64() / then.as_secs_f64() - 1.0) * 100.0
+                    ),
+                    _ => String::from("-"),
+                },
+                _ => String::from("-"),
+            };
+            vec![
+                format!("Day {:02} {}", r.day, r.part),
+                answer(r),
+                time(r.solve_time),
+                before.map_or(String::from("-"), answer),
+                time(before.and_then(|p| p.solve_time)),
+                change,
+            ]
+        })
+        .collect();
+    let header = ["", "Answer", "Solve", "Previous", "Solve", "Change"].map(String::from);
+    aligned(&header, &rows, &[2, 4, 5])
+}
+
+fn table(records: &[Record]) -> String {
+    // Memory columns only for builds that count allocations.
+    let with_memory = records.iter().any(|r| r.memory.is_some());
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|r| {
+            let mut row = vec![
+                r.day.to_string(),
+                r.part.number().to_string(),
+                r.answer.clone(),
+                r.parse_time
+                    .map_or(String::from("-"), |t| format!("{:.1?}", t)),
+                r.solve_time
+                    .map_or(String::from("-"), |t| format!("{:.1?}", t)),
+                r.input.clone(),
+                match r.cached {
+                    true => String::from("CACHED"),
+                    false => table_status(&r.status),
+                },
+            ];
+            if with_memory {
+                let memory = r.memory.map(|m| {
+                    [
+                        m.allocations.to_string(),
+                        human_bytes(m.bytes),
+                        human_bytes(m.peak),
+                    ]
+                });
+                row.extend(memory.unwrap_or_else(|| ["-", "-", "-"].map(String::from)));
+            }
+            row
+        })
+        .collect();
+    let mut header: Vec<String> = ["Day", "Part", "Answer", "Parse", "Solve", "Input", "Status"]
+        .map(String::from)
+        .into();
+    if with_memory {
+        header.extend(["Allocs", "Allocated", "Peak"].map(String::from));
+    }
+
+    // Numbers line up on the right, text on the left.
+    aligned(&header, &rows, &[0, 1, 3, 4, 7, 8, 9])
+}
+
+// The status with its message on one line, cut short so a long panic message keeps the table readable.
+fn table_status(status: &Status) -> String {
+    const MAX_MESSAGE: usize = 60;
+    let status_name = status.to_string().to_uppercase();
+    let message = status
+        .message()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    match message.chars().count() {
+        0 => status_name,
+        n if n > MAX_MESSAGE => {
+            let cut: String = message.chars().take(MAX_MESSAGE - 1).collect();
+            format!("{}: {}…", status_name, cut)
+        }
+        _ => format!("{}: {}", status_name, message),
+    }
+}
+
+/// Lays out `rows` below `header` in columns, with the columns in `right` aligned to the right.
+pub(crate) fn aligned(header: &[String], rows: &[Vec<String>], right: &[usize]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in std::iter::once(header).chain(rows.iter().map(Vec::as_slice)) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (cell, width))| match right.contains(&col) {
+                true => format!("{:>width$}", cell),
+                false => format!("{:<width$}", cell),
+            })
+            .collect();
+        let _ = writeln!(out, "{}", line.join("  ").trim_end());
+    }
+    out
+}
+
+fn json(records: &[Record]) -> String {
+    let objects: Vec<String> = records
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"input\": {}, \"status\": {}, \"message\": {}, \"allocs\": {}, \"alloc_bytes\": {}, \"peak_bytes\": {}, \"cached\": {}}}",
+                r.day,
+                r.part.number(),
+                json_string(&r.answer),
+                nanos(r.parse_time).unwrap_or(String::from("null")),
+                nanos(r.solve_time).unwrap_or(String::from("null")),
+                json_string(&r.input),
+                json_string(&r.status.to_string()),
+                json_string(&r.status.message()),
+                memory_field(r.memory, |m| m.allocations).unwrap_or(String::from("null")),
+                memory_field(r.memory, |m| m.bytes).unwrap_or(String::from("null")),
+                memory_field(r.memory, |m| m.peak).unwrap_or(String::from("null")),
+                r.cached
+            )
+        })
+        .collect();
+    if objects.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+fn nanos(time: Option<Duration>) -> Option<String> {
+    time.map(|t| t.as_nanos().to_string())
+}
+
+fn memory_field(memory: Option<AllocStats>, field: fn(AllocStats) -> u64) -> Option<String> {
+    memory.map(|m| field(m).to_string())
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv(records: &[Record]) -> String {
+    let mut out = String::from(
+        "day,part,answer,parse_ns,solve_ns,input,status,message,allocs,alloc_bytes,peak_bytes,cached\n",
+    );
+    for r in records {
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            r.day,
+            r.part.number(),
+            csv_field(&r.answer),
+            nanos(r.parse_time).unwrap_or_default(),
+            nanos(r.solve_time).unwrap_or_default(),
+            csv_field(&r.input),
+            r.status,
+            csv_field(&r.status.message()),
+            memory_field(r.memory, |m| m.allocations).unwrap_or_default(),
+            memory_field(r.memory, |m| m.bytes).unwrap_or_default(),
+            memory_field(r.memory, |m| m.peak).unwrap_or_default(),
+            r.cached
+        );
+    }
+    out
+}
+
+// Reads a duration as `{:?}` prints it, e.g. `1.5s` or `250.0µs`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let unit_start = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = s.split_at(unit_start);
+    let value: f64 = value.parse().ok()?;
+    let secs = match unit {
+        "s" => value,
+        "ms" => value / 1e3,
+        "µs" => value / 1e6,
+        "ns" => value / 1e9,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(secs))
+}
+
+// Splits csv text into rows of fields. Quoted fields may hold commas, quotes and line breaks.
+fn csv_rows(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            ('\r', false) => {}
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        String::from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::Json;
+
+    fn record(part: Part, answer: &str, status: Status) -> Record {
+        let ok = status.is_ok();
+        Record {
+            day: 7,
+            part,
+            answer: String::from(answer),
+            parse_time: Some(Duration::from_nanos(1500)),
+            parse_spans: Vec::new(),
+            solve_time: ok.then(|| Duration::from_micros(250)),
+            memory: ok.then_some(AllocStats {
+                allocations: 3,
+                bytes: 4096,
+                peak: 1024,
+            }),
+            spans: Vec::new(),
+            input: String::from("day07"),
+            status,
+            cached: false,
+        }
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            record(Part::One, "a, \"quoted\"\nanswer", Status::Ok),
+            record(
+                Part::Two,
+                "",
+                Status::Panic(String::from("index out of bounds, \"len\" is 0")),
+            ),
+            record(Part::Two, "", Status::Timeout(Duration::from_secs(5))),
+            record(Part::Two, "", Status::Error(String::from("line 3:\tbad"))),
+            record(Part::One, "", Status::Interrupted),
+        ]
+    }
+
+    #[test]
+    fn csv_round_trips() {
+        let records = records();
+        let text = Format::Csv.render(&records);
+        assert_eq!(Record::parse_csv(&text).unwrap(), records);
+    }
+
+    #[test]
+    fn csv_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn csv_rejects_short_rows() {
+        assert!(Record::parse_csv("header\n7,1,42\n").is_err());
+        assert!(Record::parse_csv("").is_err());
+    }
+
+    #[test]
+    fn json_escapes_and_parses_back() {
+        let records = records();
+        let json = Json::parse(&Format::Json.render(&records)).unwrap();
+        let Json::Array(items) = json else {
+            panic!("expected an array, got {:?}", json);
+        };
+        assert_eq!(items.len(), records.len());
+        assert_eq!(
+            items[0].get("answer").and_then(Json::as_str),
+            Some("a, \"quoted\"\nanswer")
+        );
+        assert_eq!(items[0].get("allocs").and_then(Json::as_u64), Some(3));
+        assert_eq!(items[1].get("status").and_then(Json::as_str), Some("panic"));
+        assert_eq!(
+            items[3].get("message").and_then(Json::as_str),
+            Some("line 3:\tbad")
+        );
+        assert_eq!(items[1].get("solve_ns"), Some(&Json::Null));
+        assert_eq!(Format::Json.render(&[]), "[]\n");
+    }
+
+    #[test]
+    fn table_shows_why_a_part_failed() {
+        let table = Format::Table.render(&records());
+        assert!(table.contains("PANIC: index out of bounds, \"len\" is 0"));
+        assert!(table.contains("TIMEOUT: no answer after 5.0s"));
+        assert!(table.contains("ERROR: line 3: bad"));
+        assert!(table.contains("  OK  "));
+
+        let long = Status::Panic("x".repeat(200));
+        let shown = table_status(&long);
+        assert_eq!(shown.chars().count(), "PANIC: ".len() + 60);
+        assert!(shown.ends_with('…'));
+    }
+
+    #[test]
+    fn table_lines_times_up_on_the_right() {
+        let records = [
+            record(Part::One, "1", Status::Ok),
+            Record {
+                parse_time: Some(Duration::from_millis(1234)),
+                ..record(Part::Two, "2", Status::Ok)
+            },
+        ];
+        let table = Format::Table.render(&records);
+        let lines: Vec<&str> = table.lines().collect();
+        let end = |line: &str, cell: &str| {
+            let start = line.find(cell).unwrap();
+            line[..start].chars().count() + cell.chars().count()
+        };
+        assert_eq!(end(lines[0], "Parse"), end(lines[1], "1.5µs"));
+        assert_eq!(end(lines[0], "Parse"), end(lines[2], "1.2s"));
+        assert_eq!(end(lines[0], "Solve"), end(lines[1], "250.0µs"));
+    }
+
+    #[test]
+    fn json_escapes_control_characters() {
+        assert_eq!(json_string("a\u{1}b"), "\"a\\u0001b\"");
+    }
+
+    #[test]
+    fn durations_read_back_as_printed() {
+        for d in [
+            Duration::from_secs(2),
+            Duration::from_millis(15),
+            Duration::from_micros(250),
+            Duration::from_nanos(80),
+        ] {
+            assert_eq!(parse_duration(&format!("{:?}", d)), Some(d));
+        }
+        assert_eq!(parse_duration("5 parsecs"), None);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::cache::ResultCache;
+use crate::days::Day;
+use crate::memory;
+use crate::progress::{self, Progress};
+use crate::{read_lines, run_timed, InputSource, Part, Record, Status, TimedResult};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long the runner lets solutions run, and whether it shows their progress.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    /// Draw a progress line on stderr for solutions that tick a [`Ticker`](crate::progress::Ticker).
+    pub show_progress: bool,
+}
+
+/// How a piece of work run by [`isolate`] ended.
+#[derive(Debug)]
+pub enum Isolated<T> {
+    Done(T),
+    Panicked(String),
+    TimedOut,
+    Interrupted,
+}
+
+// How often the progress line is redrawn, and how long cancelled work gets to notice.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+const CANCEL_GRACE: Duration = Duration::from_secs(1);
+
+/// Runs `work` on its own thread so a panic or a hang cannot take the caller down with it.
+///
+/// When the timeout expires or Ctrl-C is pressed, loops ticking a `Ticker` are asked to stop.
+/// Work that does not stop is left running in the background.
+pub fn isolate<T, F>(label: &str, limits: Limits, work: F) -> Isolated<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let progress = Arc::new(Progress::new(label));
+    let (tx, rx) = mpsc::channel();
+    let thread_progress = Arc::clone(&progress);
+    let spawned = thread::Builder::new().spawn(move || {
+        progress::install(thread_progress);
+        let _ = tx.send(panic::catch_unwind(AssertUnwindSafe(work)));
+    });
+    if let Err(e) = spawned {
+        return Isolated::Panicked(format!("cannot start a thread: {}", e));
+    }
+
+    let deadline = limits.timeout.map(|timeout| Instant::now() + timeout);
+    let mut drawn = false;
+    let outcome = loop {
+        let wait = match deadline {
+            Some(deadline) => POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())),
+            None => POLL_INTERVAL,
+        };
+        let stop = match rx.recv_timeout(wait) {
+            Ok(Ok(result)) => break Isolated::Done(result),
+            Ok(Err(payload)) => break Isolated::Panicked(panic_message(payload)),
+            Err(RecvTimeoutError::Disconnected) => {
+                break Isolated::Panicked(String::from("thread ended without a result"))
+            }
+            Err(RecvTimeoutError::Timeout) if progress::interrupted() => Isolated::Interrupted,
+            Err(RecvTimeoutError::Timeout) if deadline.is_some_and(|d| Instant::now() >= d) => {
+                Isolated::TimedOut
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                // Drawing allocates, which a build counting allocations would charge to the work.
+                if limits.show_progress && !memory::counting() && progress.started() {
+                    eprint!("\r\x1b[K{}", progress);
+                    drawn = true;
+                }
+                continue;
+            }
+        };
+        progress.cancel();
+        let _ = rx.recv_timeout(CANCEL_GRACE);
+        break stop;
+    };
+
+    if drawn {
+        eprint!("\r\x1b[K");
+    }
+    outcome
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(msg) => String::from(*msg),
+            Err(_) => String::from("panicked without a message"),
+        },
+    }
+}
+
+/// Reads, parses and solves one day, each step isolated and limited to `timeout`.
+/// Failures end up in the status of the records, so there is always one record per part.
+pub fn run_day(
+    day: &'static Day,
+    source: &InputSource,
+    parts: &[Part],
+    limits: Limits,
+) -> Vec<Record> {
+    let failed = |status| Record::failed(day.number, parts, &source.id(), status);
+
+    if progress::interrupted() {
+        return failed(Status::Interrupted);
+    }
+
+    let lines = match read_lines(source) {
+        Ok(lines) => lines.collect(),
+        Err(e) => return failed(Status::Error(e.to_string())),
+    };
+    let label = format!("Day {:02} parse", day.number);
+    let parsed = isolate(&label, limits, move || run_timed(|| day.parse(lines)));
+    let (input, parse_time, parse_spans) = match parsed {
+        Isolated::Done(TimedResult {
+            res: Ok(input),
+            time,
+            spans,
+            ..
+        }) => (Arc::new(input), time, spans),
+        Isolated::Done(TimedResult { res: Err(e), .. }) => {
+            return failed(Status::Error(e.to_string()))
+        }
+        other => return failed(stopped_status(other, limits)),
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let input = Arc::clone(&input);
+            let label = format!("Day {:02} {}", day.number, part);
+            let solved = match progress::interrupted() {
+                true => Isolated::Interrupted,
+                false => isolate(&label, limits, move || {
+                    run_timed(|| day.solve(&input, part))
+                }),
+            };
+            let (answer, solve_time, memory, spans, status) = match solved {
+                Isolated::Done(TimedResult {
+                    res,
+                    time,
+                    memory,
+                    spans,
+                }) => (res, Some(time), memory, spans, Status::Ok),
+                other => (
+                    String::new(),
+                    None,
+                    None,
+                    Vec::new(),
+                    stopped_status(other, limits),
+                ),
+            };
+            Record {
+                day: day.number,
+                part,
+                answer,
+                parse_time: Some(parse_time),
+                parse_spans: parse_spans.clone(),
+                solve_time,
+                memory,
+                spans,
+                input: source.id(),
+                status,
+                cached: false,
+            }
+        })
+        .collect()
+}
+
+/// Like [`run_day`], but answers from `cache` when the input and the solution did not change since
+/// they were cached, and caches what this run solved.
+pub fn run_day_cached(
+    day: &'static Day,
+    source: &InputSource,
+    parts: &[Part],
+    limits: Limits,
+    cache: &ResultCache,
+) -> Vec<Record> {
+    let key = cache.key(day, source);
+    if let Some(records) = key.and_then(|key| cache.lookup(key, source, parts)) {
+        return records;
+    }
+    let records = run_day(day, source, parts, limits);
+    if let Some(key) = key {
+        // The cache only saves time; a run that cannot be cached is still a good run.
+        let _ = cache.store(key, source, &records);
+    }
+    records
+}
+
+// The status of work that did not come back with a result.
+fn stopped_status<T>(isolated: Isolated<T>, limits: Limits) -> Status {
+    match isolated {
+        Isolated::Panicked(msg) => Status::Panic(msg),
+        Isolated::TimedOut => Status::Timeout(limits.timeout.unwrap_or_default()),
+        Isolated::Interrupted | Isolated::Done(_) => Status::Interrupted,
+    }
+}
+
+/// Runs `job` on every item using up to `jobs` threads. Results come back in the order of `items`,
+/// whichever order the jobs finish in.
+pub fn parallel_map<T, R, F>(items: &[T], jobs: usize, job: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let tx = tx.clone();
+            let (next, job) = (&next, &job);
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(idx) else {
+                    break;
+                };
+                if tx.send((idx, job(item))).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(tx);
+
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    for (idx, result) in rx {
+        results[idx] = Some(result);
+    }
+    results
+        .into_iter()
+        .map(|r| r.expect("every item is run exactly once"))
+        .collect()
+}
@@ -0,0 +1,131 @@
+use crate::{day_path, example_path, AocError, DEFAULT_EXAMPLE};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const SOLUTION_TEMPLATE: &str = r#"use crate::{AocError, Solution, Unsolved};
+use std::fmt::Display;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    // TODO: the parsed form of the puzzle input.
+    type Input = Vec<String>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, AocError> {
+        Ok(lines.collect())
+    }
+
+    fn part1(_input: &Self::Input) -> impl Display {
+        Unsolved
+    }
+
+    fn part2(_input: &Self::Input) -> impl Display {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_lines, InputSource, DEFAULT_EXAMPLE};
+
+    fn example() -> <DayNN as Solution>::Input {
+        let lines = read_lines(InputSource::example(N, DEFAULT_EXAMPLE)).unwrap();
+        DayNN::parse(lines).unwrap()
+    }
+
+    #[test]
+    #[ignore = "TODO: fill in the answer the puzzle gives for the example"]
+    fn part1_example() {
+        assert_eq!(DayNN::part1(&example()).to_string(), "TODO");
+    }
+
+    #[test]
+    #[ignore = "TODO: fill in the answer the puzzle gives for the example"]
+    fn part2_example() {
+        assert_eq!(DayNN::part2(&example()).to_string(), "TODO");
+    }
+}
+"#;
+
+/// The source file of `day`'s solution in the crate at `root`.
+pub fn solution_path(root: &Path, day: u8) -> PathBuf {
+    root.join("src")
+        .join("days")
+        .join(format!("day{:02}.rs", day))
+}
+
+/// Starts `day` in the crate at `root`: a `Solution` skeleton with a test module, registered with
+/// the runner, and empty input and example files when those do not exist yet. `aoc fetch` and
+/// `aoc examples` fill those in rather than keeping them. Returns the files it created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, AocError> {
+    let mut touched = Vec::new();
+
+    let solution = solution_path(root, day);
+    let source = SOLUTION_TEMPLATE
+        .replace("DayNN", &format!("Day{:02}", day))
+        .replace("(N, ", &format!("({}, ", day));
+    // Never overwrite a solution, even one that is not registered.
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&solution)?
+        .write_all(source.as_bytes())?;
+    touched.push(solution);
+
+    let registry = root.join("src").join("days").join("mod.rs");
+    let text = fs::read_to_string(&registry)?;
+    let text = insert_in_order(
+        &text,
+        day,
+        "pub mod day",
+        &format!("pub mod day{:02};", day),
+    );
+    let text = insert_in_order(
+        &text,
+        day,
+        "    Day::new::<day",
+        &format!(
+            "    Day::new::<day{0:02}::Day{0:02}>({0}, include_str!(\"day{0:02}.rs\")),",
+            day
+        ),
+    );
+    fs::write(&registry, text)?;
+    touched.push(registry);
+
+    for path in [day_path(day), example_path(day, DEFAULT_EXAMPLE)] {
+        if !path.exists() {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, "")?;
+            touched.push(path);
+        }
+    }
+    Ok(touched)
+}
+
+// Puts `line` among the lines starting with `prefix` followed by a day number, keeping them sorted.
+fn insert_in_order(text: &str, day: u8, prefix: &str, line: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let numbered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, l)| {
+            let digits: String = l
+                .strip_prefix(prefix)?
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            Some((idx, digits.parse().ok()?))
+        })
+        .collect();
+
+    let at = match numbered.iter().find(|(_, n)| *n > day) {
+        Some((idx, _)) => *idx,
+        None => numbered.last().map_or(lines.len(), |(idx, _)| idx + 1),
+    };
+    lines.insert(at, line);
+    lines.join("\n") + "\n"
+}
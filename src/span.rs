@@ -0,0 +1,156 @@
+use crate::memory;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+/// A named phase of a solution, e.g. "build graph" or "search", with the phases opened inside it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SpanTree {
+    pub name: &'static str,
+    /// Summed over every time the span was entered.
+    pub time: Duration,
+    pub count: u64,
+    pub children: Vec<SpanTree>,
+}
+
+#[derive(Debug)]
+struct Node {
+    name: &'static str,
+    parent: Option<usize>,
+    time: Duration,
+    count: u64,
+}
+
+#[derive(Debug, Default)]
+struct Collector {
+    nodes: Vec<Node>,
+    /// The spans currently entered, innermost last.
+    open: Vec<usize>,
+}
+
+impl Collector {
+    fn enter(&mut self, name: &'static str) {
+        let parent = self.open.last().copied();
+        // Entering the same span again, e.g. in a loop, adds up into one node.
+        let idx = match self
+            .nodes
+            .iter()
+            .position(|n| n.parent == parent && n.name == name)
+        {
+            Some(idx) => idx,
+            None => {
+                self.nodes.push(Node {
+                    name,
+                    parent,
+                    time: Duration::ZERO,
+                    count: 0,
+                });
+                self.nodes.len() - 1
+            }
+        };
+        self.nodes[idx].count += 1;
+        self.open.push(idx);
+    }
+
+    fn exit(&mut self, time: Duration) {
+        if let Some(idx) = self.open.pop() {
+            self.nodes[idx].time += time;
+        }
+    }
+
+    fn tree(&self, parent: Option<usize>) -> Vec<SpanTree> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.parent == parent)
+            .map(|(idx, n)| SpanTree {
+                name: n.name,
+                time: n.time,
+                count: n.count,
+                children: self.tree(Some(idx)),
+            })
+            .collect()
+    }
+}
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Collector>> = const { RefCell::new(None) };
+}
+
+pub struct Span {
+    start: Option<Instant>,
+    // Spans are tracked per thread, so one must end on the thread that entered it.
+    _not_send: PhantomData<*const ()>,
+}
+
+/// Enters the span `name` until the returned guard is dropped:
+///
+/// ```ignore
+/// let _span = span::enter("build graph");
+/// ```
+///
+/// Spans only cost something inside [`collect`], i.e. when run by the runner. Spans entered on
+/// threads a solution spawns itself are not recorded.
+pub fn enter(name: &'static str) -> Span {
+    let collecting = COLLECTOR.with(|collector| match collector.borrow_mut().as_mut() {
+        Some(collector) => {
+            memory::uncounted(|| collector.enter(name));
+            true
+        }
+        None => false,
+    });
+    Span {
+        start: collecting.then(Instant::now),
+        _not_send: PhantomData,
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let time = start.elapsed();
+            COLLECTOR.with(|collector| {
+                if let Some(collector) = collector.borrow_mut().as_mut() {
+                    memory::uncounted(|| collector.exit(time));
+                }
+            });
+        }
+    }
+}
+
+/// Runs `func` inside the span `name`, for phases that are a single expression.
+pub fn time<T, F: FnOnce() -> T>(name: &'static str, func: F) -> T {
+    let _span = enter(name);
+    func()
+}
+
+/// Runs `func`, recording the spans it enters on this thread.
+pub fn collect<T, F: FnOnce() -> T>(func: F) -> (T, Vec<SpanTree>) {
+    let outer = COLLECTOR.with(|collector| collector.replace(Some(Collector::default())));
+    let res = func();
+    let collected = COLLECTOR.with(|collector| collector.replace(outer));
+    let spans = collected.map(|c| c.tree(None)).unwrap_or_default();
+    (res, spans)
+}
+
+pub(crate) fn draw(out: &mut String, children: &[SpanTree], root: Duration, indent: &str) {
+    for (idx, child) in children.iter().enumerate() {
+        let last = idx + 1 == children.len();
+        let (branch, below) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        out.push_str(&format!("{}{}{}\n", indent, branch, line(child, root)));
+        draw(out, &child.children, root, &format!("{}{}", indent, below));
+    }
+}
+
+fn line(span: &SpanTree, root: Duration) -> String {
+    let share = 100.0 * span.time.as_secs_f64() / root.as_secs_f64().max(1e-12);
+    let mut line = format!("{}: {:.1?} ({:.1}%)", span.name, span.time, share);
+    if span.count > 1 {
+        line.push_str(&format!(", {}×", span.count));
+    }
+    line
+}
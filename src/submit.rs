@@ -0,0 +1,372 @@
+use crate::{html, http};
+use crate::{Answers, AocError, Config, Part};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Too soon after the previous answer; try again after the wait.
+    RateLimited(Duration),
+    /// The part was solved before, or the first part still needs solving.
+    WrongLevel,
+    /// A response none of the above matched, with its text.
+    Unrecognised(String),
+}
+
+impl Verdict {
+    pub fn parse(page: &str) -> Verdict {
+        let text = article_text(page);
+        if text.contains("That's the right answer") {
+            Verdict::Right
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited(wait_time(&text).unwrap_or_default())
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unrecognised(text)
+        }
+    }
+
+    /// Whether the answer is known to be wrong, so it must not be sent again.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn from_recorded(s: &str) -> Option<Verdict> {
+        match s {
+            "wrong" => Some(Verdict::Wrong),
+            "too high" => Some(Verdict::TooHigh),
+            "too low" => Some(Verdict::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited, wait {:.0?}", wait),
+            Verdict::WrongLevel => write!(f, "not the right level, already solved?"),
+            Verdict::Unrecognised(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+// The verdict is in the page's only <article>, with some markup in between.
+fn article_text(page: &str) -> String {
+    let text = match html::elements(page, "article").first() {
+        Some(article) => html::text(article),
+        None => html::text(page),
+    };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "You have 1m 5s left to wait."
+fn wait_time(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, amount) = before.rsplit_once("You have ")?;
+    amount
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |wait, part| {
+            let unit = part.trim_start_matches(|c: char| c.is_ascii_digit());
+            let value: u64 = part[..part.len() - unit.len()].parse().ok()?;
+            let secs = match unit {
+                "h" => value * 3600,
+                "m" => value * 60,
+                "s" => value,
+                _ => return None,
+            };
+            Some(wait + Duration::from_secs(secs))
+        })
+}
+
+/// The verdict on an answer, and whether it came from the server or from an earlier submission.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// Nothing was sent, the verdict follows from what earlier submissions recorded.
+    pub recorded: bool,
+}
+
+/// Submits `answer` for `part` of `day`, unless earlier submissions recorded in `answers` already
+/// tell how it would go. Right and wrong answers are recorded in `answers`, which the caller saves.
+pub fn submit_answer(
+    config: &Config,
+    answers: &mut Answers,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Submission, AocError> {
+    if let Some(verdict) = recorded_verdict(answers, part, answer) {
+        return Ok(Submission {
+            verdict,
+            recorded: true,
+        });
+    }
+
+    let url = format!("{}/{}/day/{}/answer", config.base_url, config.year, day);
+    let cookie = format!("session={}", config.session()?);
+    let headers = [
+        ("Cookie", cookie.as_str()),
+        ("User-Agent", config.user_agent.as_str()),
+        ("Content-Type", "application/x-www-form-urlencoded"),
+    ];
+    let body = format!("level={}&answer={}", part.number(), form_encode(answer));
+    let response = http::request("POST", &url, &headers, Some(&body))?;
+    if !response.is_success() {
+        return Err(AocError::Server(format!(
+            "POST {} answered {}: {}",
+            url,
+            response.status,
+            response.body.trim()
+        )));
+    }
+
+    let verdict = Verdict::parse(&response.body);
+    match verdict {
+        Verdict::Right => {
+            answers.set_answer(part, answer);
+            answers.set(&submitted_key(part), answer);
+        }
+        ref wrong if wrong.is_wrong() => {
+            let mut entries = wrong_answers(answers, part);
+            entries.push((String::from(answer), wrong.clone()));
+            let text: Vec<String> = entries
+                .iter()
+                .map(|(answer, verdict)| format!("{}: {}", answer, verdict))
+                .collect();
+            answers.set(&wrong_key(part), &text.join("\n"));
+        }
+        _ => {}
+    }
+    Ok(Submission {
+        verdict,
+        recorded: false,
+    })
+}
+
+// The answer the server accepted is kept as `part1_submitted = "123"`. Unlike `part1`, which
+// `aoc check --record` writes as well, it is only ever set by the server's verdict.
+fn submitted_key(part: Part) -> String {
+    format!("part{}_submitted", part.number())
+}
+
+// Wrong answers are kept as `part1_wrong = "123: too high\n45: too low"`.
+fn wrong_key(part: Part) -> String {
+    format!("part{}_wrong", part.number())
+}
+
+fn wrong_answers(answers: &Answers, part: Part) -> Vec<(String, Verdict)> {
+    answers
+        .get(&wrong_key(part))
+        .unwrap_or("")
+        .lines()
+        .filter_map(|line| {
+            let (answer, verdict) = line.split_once(": ")?;
+            Some((String::from(answer), Verdict::from_recorded(verdict)?))
+        })
+        .collect()
+}
+
+fn recorded_verdict(answers: &Answers, part: Part, answer: &str) -> Option<Verdict> {
+    if let Some(right) = answers.get(&submitted_key(part)) {
+        return Some(match right == answer {
+            true => Verdict::Right,
+            false => Verdict::Wrong,
+        });
+    }
+
+    let wrong = wrong_answers(answers, part);
+    if let Some((_, verdict)) = wrong.iter().find(|(a, _)| a == answer) {
+        return Some(verdict.clone());
+    }
+    // Anything above an answer that was too high is too high as well, and likewise for too low.
+    let value: i128 = answer.parse().ok()?;
+    wrong.iter().find_map(|(a, verdict)| {
+        let bound: i128 = a.parse().ok()?;
+        match verdict {
+            Verdict::TooHigh if value >= bound => Some(Verdict::TooHigh),
+            Verdict::TooLow if value <= bound => Some(Verdict::TooLow),
+            _ => None,
+        }
+    })
+}
+
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                String::from(b as char)
+            }
+            b' ' => String::from("+"),
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{StubServer, TempDir};
+    use crate::InputSource;
+
+    const RIGHT: &str = "<main><article><p>That's the right answer!  You are one gold star closer to restoring snow operations.</p></article></main>";
+    const WRONG: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  Please wait one minute.</p></article></main>";
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  Please wait one minute.</p></article></main>";
+    const TOO_SOON: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article></main>";
+    const WRONG_LEVEL: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>";
+
+    fn config(server: &StubServer) -> Config {
+        Config {
+            session: Some(String::from("c0ffee")),
+            base_url: String::from(server.url()),
+            ..Config::default()
+        }
+    }
+
+    fn answers(dir: &TempDir) -> Answers {
+        Answers::load(&InputSource::File(dir.path().join("inputs").join("day01"))).unwrap()
+    }
+
+    fn submit(server: &StubServer, answers: &mut Answers, answer: &str) -> Verdict {
+        let submission = submit_answer(&config(server), answers, 1, Part::One, answer).unwrap();
+        assert!(!submission.recorded, "{} was not sent", answer);
+        submission.verdict
+    }
+
+    fn known(answers: &mut Answers, answer: &str) -> Verdict {
+        // No server: nothing may be sent.
+        let config = Config {
+            session: Some(String::from("c0ffee")),
+            base_url: String::from("http://127.0.0.1:1"),
+            ..Config::default()
+        };
+        let submission = submit_answer(&config, answers, 1, Part::One, answer).unwrap();
+        assert!(submission.recorded, "{} was sent", answer);
+        submission.verdict
+    }
+
+    #[test]
+    fn records_a_right_answer() {
+        let dir = TempDir::new("submit");
+        let server = StubServer::start(vec![(200, RIGHT)]);
+        let mut answers = answers(&dir);
+
+        assert_eq!(submit(&server, &mut answers, "142"), Verdict::Right);
+        assert_eq!(answers.answer(Part::One), Some("142"));
+        assert_eq!(answers.get("part1_submitted"), Some("142"));
+        assert_eq!(known(&mut answers, "142"), Verdict::Right);
+        assert_eq!(known(&mut answers, "143"), Verdict::Wrong);
+
+        let request = &server.requests()[0];
+        assert!(
+            request.starts_with("POST /2023/day/1/answer HTTP/1.1\r\n"),
+            "{}",
+            request
+        );
+        assert!(request.contains("Cookie: session=c0ffee\r\n"));
+        assert!(request.contains("Content-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=142"));
+    }
+
+    #[test]
+    fn records_a_wrong_answer() {
+        let dir = TempDir::new("submit");
+        let server = StubServer::start(vec![(200, WRONG)]);
+        let mut answers = answers(&dir);
+
+        assert_eq!(submit(&server, &mut answers, "17"), Verdict::Wrong);
+        assert_eq!(answers.answer(Part::One), None);
+        assert_eq!(answers.get("part1_wrong"), Some("17: wrong"));
+        assert_eq!(known(&mut answers, "17"), Verdict::Wrong);
+    }
+
+    #[test]
+    fn bounds_later_answers_by_too_high_and_too_low() {
+        let dir = TempDir::new("submit");
+        let server = StubServer::start(vec![(200, TOO_HIGH), (200, TOO_LOW)]);
+        let mut answers = answers(&dir);
+
+        assert_eq!(submit(&server, &mut answers, "500"), Verdict::TooHigh);
+        assert_eq!(known(&mut answers, "501"), Verdict::TooHigh);
+        assert_eq!(submit(&server, &mut answers, "100"), Verdict::TooLow);
+        assert_eq!(known(&mut answers, "99"), Verdict::TooLow);
+        assert_eq!(
+            answers.get("part1_wrong"),
+            Some("500: too high\n100: too low")
+        );
+
+        // Saved and loaded again, the verdicts still hold.
+        answers.save().unwrap();
+        let mut reloaded = self::answers(&dir);
+        assert_eq!(known(&mut reloaded, "600"), Verdict::TooHigh);
+    }
+
+    #[test]
+    fn records_nothing_when_rate_limited() {
+        let dir = TempDir::new("submit");
+        let server = StubServer::start(vec![(200, TOO_SOON), (200, RIGHT)]);
+        let mut answers = answers(&dir);
+
+        assert_eq!(
+            submit(&server, &mut answers, "142"),
+            Verdict::RateLimited(Duration::from_secs(65))
+        );
+        assert_eq!(answers.get("part1_wrong"), None);
+        assert_eq!(submit(&server, &mut answers, "142"), Verdict::Right);
+    }
+
+    #[test]
+    fn reports_the_wrong_level() {
+        let dir = TempDir::new("submit");
+        let server = StubServer::start(vec![(200, WRONG_LEVEL)]);
+        let mut answers = answers(&dir);
+
+        assert_eq!(submit(&server, &mut answers, "142"), Verdict::WrongLevel);
+        assert_eq!(answers.answer(Part::One), None);
+    }
+
+    #[test]
+    fn sends_answers_only_check_recorded() {
+        let dir = TempDir::new("submit");
+        let server = StubServer::start(vec![(200, WRONG)]);
+        let mut answers = answers(&dir);
+        answers.set_answer(Part::One, "142");
+
+        assert_eq!(submit(&server, &mut answers, "142"), Verdict::Wrong);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn fails_on_a_server_error() {
+        let dir = TempDir::new("submit");
+        let server = StubServer::start(vec![(500, "oops")]);
+        let err =
+            submit_answer(&config(&server), &mut answers(&dir), 1, Part::One, "142").unwrap_err();
+        assert!(matches!(err, AocError::Server(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn encodes_the_answer() {
+        assert_eq!(form_encode("a b&c=d"), "a+b%26c%3Dd");
+        assert_eq!(form_encode("-12_3.4~"), "-12_3.4~");
+    }
+}
@@ -0,0 +1,148 @@
+//! Helpers for the unit tests: scratch directories, environment variables and a stub puzzle server.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+/// A directory of its own for a test, removed again when dropped.
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> TempDir {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let dir = env::temp_dir().join(format!(
+            "aoc2023-{}-{}-{}",
+            name,
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Sets environment variables until dropped. The environment is shared by every test in the
+/// process, so only one test at a time gets to change it.
+pub(crate) struct EnvGuard {
+    previous: Vec<(&'static str, Option<OsString>)>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+pub(crate) fn set_env(vars: &[(&'static str, &Path)]) -> EnvGuard {
+    static LOCK: Mutex<()> = Mutex::new(());
+    // A test that failed while holding the lock leaves nothing behind: the guard restored the vars.
+    let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let previous = vars
+        .iter()
+        .map(|(name, value)| {
+            let previous = env::var_os(name);
+            env::set_var(name, value);
+            (*name, previous)
+        })
+        .collect();
+    EnvGuard {
+        previous,
+        _lock: lock,
+    }
+}
+
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        for (name, previous) in &self.previous {
+            match previous {
+                Some(value) => env::set_var(name, value),
+                None => env::remove_var(name),
+            }
+        }
+    }
+}
+
+/// An HTTP server on a local port that answers requests with canned responses, in order, and keeps
+/// the requests it got.
+pub(crate) struct StubServer {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl StubServer {
+    /// Answers one request with each `(status, body)`, then stops accepting.
+    pub fn start(responses: Vec<(u16, &str)>) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, String::from(body)))
+            .collect();
+
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+                let request = read_request(&mut reader);
+                seen.lock().unwrap().push(request);
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = reader.get_mut().write_all(response.as_bytes());
+            }
+        });
+        StubServer { url, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The requests answered so far, head and body as they were sent.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> String {
+    let mut request = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return request;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+        request.push_str(&line);
+        if line == "\r\n" {
+            break;
+        }
+    }
+    let mut body = vec![0; content_length];
+    if reader.read_exact(&mut body).is_ok() {
+        request.push_str(&String::from_utf8_lossy(&body));
+    }
+    request
+}
@@ -0,0 +1,159 @@
+use crate::AocError;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The `key = "value"` entries of a file, in the order they were read or first set.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Table {
+    path: PathBuf,
+    entries: Vec<(String, String)>,
+}
+
+impl Table {
+    /// Reads the table at `path`; a missing file is an empty table.
+    pub fn load(path: PathBuf) -> Result<Table, AocError> {
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let entries = parse(&text).map_err(|e| match e {
+            AocError::Parse { line, msg } => AocError::Parse {
+                line,
+                msg: format!("{} (in {})", msg, path.display()),
+            },
+            other => other,
+        })?;
+        Ok(Table { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.entries.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = String::from(value),
+            None => self.entries.push((String::from(key), String::from(value))),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), AocError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text: String = self
+            .entries
+            .iter()
+            .map(|(k, v)| format!("{} = {}\n", k, quote(v)))
+            .collect();
+        Ok(fs::write(&self.path, text)?)
+    }
+}
+
+/// Reads the small subset of TOML the answers and config files use: `key = "value"` lines and
+/// `#` comments.
+pub(crate) fn parse(text: &str) -> Result<Vec<(String, String)>, AocError> {
+    let mut entries = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| AocError::parse("expected key = \"value\"").on_line(idx))?;
+        let value = unquote(value.trim()).map_err(|e| e.on_line(idx))?;
+        entries.push((String::from(key.trim()), value));
+    }
+    Ok(entries)
+}
+
+// Bare values are accepted too, so `part1 = 42` reads the same as `part1 = "42"`.
+fn unquote(value: &str) -> Result<String, AocError> {
+    let Some(inner) = value.strip_prefix('"') else {
+        return Ok(String::from(value));
+    };
+    let inner = inner
+        .strip_suffix('"')
+        .ok_or_else(|| AocError::parse(format!("unterminated string {}", value)))?;
+
+    let mut unquoted = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(escaped @ ('"' | '\\')) => unquoted.push(escaped),
+            Some('n') => unquoted.push('\n'),
+            Some(other) => return Err(AocError::parse(format!("unsupported escape \\{}", other))),
+            None => return Err(AocError::parse("string ends in a lone \\")),
+        }
+    }
+    Ok(unquoted)
+}
+
+pub(crate) fn quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let text = "# answers\n\npart1 = \"42\"\n  # indented comment\npart2 = 17\n";
+        let entries = parse(text).unwrap();
+        assert_eq!(
+            entries,
+            [
+                (String::from("part1"), String::from("42")),
+                (String::from("part2"), String::from("17"))
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_escapes() {
+        let entries = parse(r#"key = "a \"b\" \\ c\nd""#).unwrap();
+        assert_eq!(entries[0].1, "a \"b\" \\ c\nd");
+    }
+
+    #[test]
+    fn quote_round_trips() {
+        for value in [
+            "",
+            "plain",
+            "with \"quotes\"",
+            "back\\slash",
+            "two\nlines",
+            "# = x",
+        ] {
+            let text = format!("key = {}\n", quote(value));
+            assert_eq!(parse(&text).unwrap()[0].1, value, "{}", text);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(parse("no equals sign").is_err());
+        assert!(parse("key = \"unterminated").is_err());
+        assert!(parse(r#"key = "\t""#).is_err());
+        assert!(parse(r#"key = "ends in \""#).is_err());
+    }
+}